@@ -0,0 +1,72 @@
+//! Plain data describing how a nutype is defined.
+//!
+//! Every nutype has `CONSTRAINTS` associated constant, so tooling (e.g. generators of
+//! client-side validation or docs) can read the configured sanitizers and validators:
+//!
+//! ```
+//! use nutype::nutype;
+//! use nutype::constraints::{Rule, Value};
+//!
+//! #[nutype(sanitize(trim) validate(not_empty, max_len = 20))]
+//! pub struct Username(String);
+//!
+//! assert_eq!(Username::CONSTRAINTS.inner_type, "String");
+//! assert_eq!(
+//!     Username::CONSTRAINTS.validator("max_len"),
+//!     Some(&Rule { name: "max_len", args: &[Value::UInt(20)] })
+//! );
+//! ```
+
+/// Describes the inner type, the sanitizers and the validators of a nutype.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Constraints {
+    /// The inner type as it's written in the definition, e.g. `"String"` or `"u32"`.
+    pub inner_type: &'static str,
+
+    /// Sanitizers in the order they are applied.
+    pub sanitizers: &'static [Rule],
+
+    /// Validators in the order they are checked.
+    pub validators: &'static [Rule],
+}
+
+impl Constraints {
+    /// Finds a sanitizer by its name, e.g. `"trim"`.
+    pub fn sanitizer(&self, name: &str) -> Option<&'static Rule> {
+        self.sanitizers.iter().find(|rule| rule.name == name)
+    }
+
+    /// Finds a validator by its name, e.g. `"max_len"`.
+    pub fn validator(&self, name: &str) -> Option<&'static Rule> {
+        self.validators.iter().find(|rule| rule.name == name)
+    }
+}
+
+/// A sanitizer or a validator together with its arguments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rule {
+    /// The name as it's written in `#[nutype(...)]`, e.g. `"trim"` or `"max_len"`.
+    /// Custom sanitizers and validators are named `"with"`.
+    pub name: &'static str,
+
+    /// The arguments, e.g. `[Value::UInt(20)]` for `max_len = 20` or
+    /// `[Value::Int(-10), Value::Int(10)]` for `clamp(-10, 10)`.
+    /// A named custom validator has its name as the argument, e.g. `[Value::Str("NotReserved")]`.
+    pub args: &'static [Value],
+}
+
+/// An argument of a [`Rule`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    /// A value of a signed integer type.
+    Int(i128),
+
+    /// A value of an unsigned integer type or a length.
+    UInt(u128),
+
+    /// A value of a float type.
+    Float(f64),
+
+    /// A string, e.g. a regex pattern.
+    Str(&'static str),
+}
@@ -108,6 +108,7 @@
 //! * String
 //! * Integer (`u8`, `u16`,`u32`, `u64`, `u128`, `i8`, `i16`, `i32`, `i64`, `i128`, `usize`, `isize`)
 //! * Float (`f32`, `f64`)
+//! * Any other type (e.g. `Uuid`, `PathBuf`, `Vec<u8>` or your own struct)
 //!
 //! ## String
 //!
@@ -124,17 +125,22 @@
 //!
 //! ### String validators
 //!
-//! | Validator   | Description                                                                     | Error variant | Example                              |
-//! |-------------|---------------------------------------------------------------------------------|---------------|--------------------------------------|
-//! | `max_len`   | Max length of the string                                                        | `TooLong`     | `max_len = 255`                      |
-//! | `min_len`   | Min length of the string                                                        | `TooShort`    | `min_len = 5`                        |
-//! | `not_empty` | Rejects an empty string                                                         | `Empty`       | `not_empty`                          |
-//! | `with`      | Custom validator. A function or closure that receives `&str` and returns `bool` | `Invalid`     | `with = \|s: &str\| s.contains('@')` |
+//! | Validator          | Description                                                                     | Error variant       | Example                              |
+//! |--------------------|---------------------------------------------------------------------------------|---------------------|--------------------------------------|
+//! | `max_len`          | Max length of the string (in bytes)                                             | `TooLong`           | `max_len = 255`                      |
+//! | `min_len`          | Min length of the string (in bytes)                                             | `TooShort`          | `min_len = 5`                        |
+//! | `char_len_max`     | Max length of the string (in chars)                                             | `TooLongChars`      | `char_len_max = 255`                 |
+//! | `char_len_min`     | Min length of the string (in chars)                                             | `TooShortChars`     | `char_len_min = 5`                   |
+//! | `grapheme_len_max` | Max length of the string (in grapheme clusters). Requires `graphemes` feature.  | `TooLongGraphemes`  | `grapheme_len_max = 255`             |
+//! | `grapheme_len_min` | Min length of the string (in grapheme clusters). Requires `graphemes` feature.  | `TooShortGraphemes` | `grapheme_len_min = 5`               |
+//! | `not_empty`        | Rejects an empty string                                                         | `Empty`             | `not_empty`                          |
+//! | `with`             | Custom validator. A function or closure that receives `&str` and returns `bool` | `Invalid`           | `with = \|s: &str\| s.contains('@')` |
+//! | `regex`            | Validates format with a regex. Requires `regex` feature.                        | `RegexMismatch`     | `regex = "^[0-9]{7}$"`               |
 //!
 //! ### String derivable traits
 //!
 //! The following traits can be derived for a string-based type:
-//! `Debug`, `Clone`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `FromStr`, `AsRef`, `From`, `TryFrom`, `Into`, `Hash`, `Borrow`, `Display`, `Serialize`, `Deserialize`.
+//! `Debug`, `Clone`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `FromStr`, `AsRef`, `Deref`, `From`, `TryFrom`, `Into`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`, `JsonSchema`, `SqlxType`.
 //!
 //!
 //! ## Integer
@@ -143,22 +149,30 @@
 //!
 //! ### Integer sanitizers
 //!
-//! | Sanitizer | Description       | Example                            |
-//! |-----------|-------------------|------------------------------------|
-//! | `with`    | Custom sanitizer. | `with = \|raw\| raw.clamp(0, 100)` |
+//! | Sanitizer | Description                                  | Example                                         |
+//! |-----------|----------------------------------------------|-------------------------------------------------|
+//! | `clamp`   | Clamps the value into the range (inclusive). | `clamp(0, 100)`                                 |
+//! | `with`    | Custom sanitizer.                            | `with = \|raw\| raw.checked_abs().unwrap_or(0)` |
+//!
+//! The range of `clamp` has to be within the `min` and `max` validators, so a clamped value is never rejected because of them.
 //!
 //! ### Integer validators
 //!
-//! | Validator | Description         | Error variant | Example                       |
-//! |-----------|---------------------|---------------|-------------------------------|
-//! | `max`     | Maximum valid value | `TooBig`      | `max = 99`                    |
-//! | `min`     | Minimum valid value | `TooSmall`    | `min = 18`                    |
-//! | `with`    | Custom validator    | `Invalid`     | `with = \|num\| num % 2 == 0` |
+//! | Validator     | Description                 | Error variant   | Example                       |
+//! |---------------|-----------------------------|-----------------|-------------------------------|
+//! | `max`         | Maximum valid value         | `TooBig`        | `max = 99`                    |
+//! | `min`         | Minimum valid value         | `TooSmall`      | `min = 18`                    |
+//! | `less`        | Value must be less than     | `TooBig`        | `less = 100`                  |
+//! | `greater`     | Value must be greater than  | `TooSmall`      | `greater = 0`                 |
+//! | `multiple_of` | Value must be a multiple of | `NotMultipleOf` | `multiple_of = 6`             |
+//! | `with`        | Custom validator            | `Invalid`       | `with = \|num\| num % 2 == 0` |
+//!
+//! `min` and `greater` (as well as `max` and `less`) cannot be used together.
 //!
 //! ### Integer derivable traits
 //!
 //! The following traits can be derived for an integer-based type:
-//! `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `FromStr`, `AsRef`, `Into`, `From`, `TryFrom`, `Hash`, `Borrow`, `Display`, `Serialize`, `Deserialize`.
+//! `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `FromStr`, `AsRef`, `Deref`, `Into`, `From`, `TryFrom`, `Hash`, `Borrow`, `Display`, `Default`, `Add`, `Sub`, `Mul`, `Div`, `Neg` (signed only), `Sum`, `Serialize`, `Deserialize`, `JsonSchema`, `SqlxType`.
 //!
 //!
 //! ## Float
@@ -167,22 +181,88 @@
 //!
 //! ### Float sanitizers
 //!
-//! | Sanitizer | Description       | Example                                |
-//! |-----------|-------------------|----------------------------------------|
-//! | `with`    | Custom sanitizer. | `with = \|val\| val.clamp(0.0, 100.0)` |
+//! | Sanitizer     | Description                                                                     | Example                      |
+//! |---------------|---------------------------------------------------------------------------------|------------------------------|
+//! | `clamp`       | Clamps the value into the range (inclusive).                                    | `clamp(0.0, 100.0)`          |
+//! | `round`       | Rounds to the given number of decimal places (to an integer without arguments). | `round(2)`                   |
+//! | `floor`       | Rounds down to an integer.                                                      | `floor`                      |
+//! | `ceil`        | Rounds up to an integer.                                                        | `ceil`                       |
+//! | `trunc`       | Removes the fractional part.                                                    | `trunc`                      |
+//! | `abs`         | Takes the absolute value.                                                       | `abs`                        |
+//! | `replace_nan` | Replaces NaN with the given value.                                              | `replace_nan(0.0)`           |
+//! | `with`        | Custom sanitizer.                                                               | `with = \|val\| val * 100.0` |
+//!
+//! The range of `clamp` has to be within the `min` and `max` validators, so a clamped value is never rejected because of them.
+//! `round(n)` accepts up to 38 decimal places for `f32` and up to 308 for `f64`. Values too big to be scaled by `10^n` are left as they are.
 //!
 //! ### Float validators
 //!
-//! | Validator | Description         | Error variant | Example                       |
-//! |-----------|---------------------|---------------|-------------------------------|
-//! | `max`     | Maximum valid value | `TooBig`      | `max = 100.0`                 |
-//! | `min`     | Minimum valid value | `TooSmall`    | `min = 0.0`                   |
-//! | `with`    | Custom validator    | `Invalid`     | `with = \|val\| val != 50.0`  |
+//! | Validator     | Description                                    | Error variant   | Example                      |
+//! |---------------|------------------------------------------------|-----------------|------------------------------|
+//! | `max`         | Maximum valid value                            | `TooBig`        | `max = 100.0`                |
+//! | `min`         | Minimum valid value                            | `TooSmall`      | `min = 0.0`                  |
+//! | `less`        | Value must be less than                        | `TooBig`        | `less = 1.0`                 |
+//! | `greater`     | Value must be greater than                     | `TooSmall`      | `greater = 0.0`              |
+//! | `multiple_of` | Value must be a multiple of (with a tolerance) | `NotMultipleOf` | `multiple_of = 0.05`         |
+//! | `finite`      | Rejects NaN and ±infinity                      | `NotFinite`     | `finite`                     |
+//! | `not_nan`     | Rejects NaN                                    | `NaN`           | `not_nan`                    |
+//! | `with`        | Custom validator                               | `Invalid`       | `with = \|val\| val != 50.0` |
+//!
+//! Note, that `NaN` is neither smaller nor greater than any number, so it passes `min` and `max` validators.
+//! If you want a float type you can trust, add the `finite` validator: it guarantees that the value is neither `NaN` nor infinite.
+//!
+//! Float numbers can't represent most decimal steps precisely, so `multiple_of` accepts values within a small tolerance of a multiple, e.g. `0.15` passes `multiple_of = 0.05`.
+//! An explicit absolute tolerance can be set with `epsilon`: `multiple_of(0.05, epsilon = 0.0001)`.
+//! NaN and infinities are never multiples.
 //!
 //! ### Float derivable traits
 //!
 //! The following traits can be derived for a float-based type:
-//! `Debug`, `Clone`, `Copy`, `PartialEq`, `PartialOrd`, `FromStr`, `AsRef`, `Into`, `From`, `TryFrom`, `Hash`, `Borrow`, `Display`, `Serialize`, `Deserialize`.
+//! `Debug`, `Clone`, `Copy`, `PartialEq`, `PartialOrd`, `FromStr`, `AsRef`, `Deref`, `Into`, `From`, `TryFrom`, `Borrow`, `Display`, `Default`, `Add`, `Sub`, `Mul`, `Div`, `Neg`, `Sum`, `Serialize`, `Deserialize`, `JsonSchema`, `SqlxType`.
+//!
+//! `Eq`, `Ord` and `Hash` can be derived only if the type has `finite` or `not_nan` validator, because `NaN` breaks the rules of equality and total ordering.
+//!
+//! ## Other inner types
+//!
+//! Any other type can be wrapped as well, e.g. `Uuid`, `Url`, `PathBuf`, `Vec<u8>` or your own struct.
+//! Since nutype knows nothing about such types, only custom sanitizers and validators (`with = ...`) are available:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(with = |mut tags: Vec<String>| { tags.sort(); tags.dedup(); tags })
+//!     validate(with = |tags| !tags.is_empty())
+//! )]
+//! #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+//! pub struct Tags(Vec<String>);
+//! ```
+//!
+//! ### Derivable traits for other inner types
+//!
+//! The following traits can be derived, as long as the inner type implements them:
+//! `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash`, `FromStr`, `AsRef`, `Deref`, `Into`, `From`, `TryFrom`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`, `JsonSchema`, `SqlxType`.
+//!
+//! `#[derive(*)]` is not supported, because nutype cannot know which traits the inner type implements.
+//!
+//! ### Generics
+//!
+//! Types with an inner type other than String, integer or float may have generic parameters, lifetimes and const generics:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//! use std::borrow::Cow;
+//!
+//! #[nutype(validate(with = |items| !items.is_empty()))]
+//! #[derive(Debug, Clone, PartialEq, AsRef, TryFrom)]
+//! pub struct NonEmpty<T>(Vec<T>);
+//!
+//! #[nutype(validate(with = |name| !name.trim().is_empty()))]
+//! #[derive(Debug, Display)]
+//! pub struct Name<'a>(Cow<'a, str>);
+//! ```
+//!
+//! The error type (e.g. `NonEmptyError`) is not generic. `FromStr` cannot be derived for generic types.
 //!
 //! ## Custom sanitizers
 //!
@@ -235,10 +315,345 @@
 //! }
 //! ```
 //!
+//! ### Named custom validators
+//!
+//! A custom validator set with `with = ...` reports the generic `Invalid` variant, and there can be only one of them.
+//! To have several custom validators that can be told apart, give each one a name.
+//! Every named validator gets its own error variant:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(
+//!     with(name = NotReserved, fn = is_not_reserved),
+//!     with(name = NoSpaces, fn = |s| !s.contains(' ')),
+//! ))]
+//! pub struct Username(String);
+//!
+//! fn is_not_reserved(name: &str) -> bool {
+//!     !["admin", "root"].contains(&name)
+//! }
+//!
+//! assert_eq!(Username::new("root"), Err(UsernameError::NotReserved));
+//! assert_eq!(Username::new("john doe"), Err(UsernameError::NoSpaces));
+//! ```
+//!
+//! The default error message is derived from the name (e.g. `not reserved`), it can be overridden with `message = "..."`.
+//! A name must not clash with a variant of another validator (e.g. `Empty` next to `not_empty`, or `Invalid` next to an unnamed `with`).
+//!
+//!
+//! ## Detailed errors
+//!
+//! By default the error variants generated for `min_len`, `max_len`, `min`, `max` and similar validators carry no data.
+//! Add the `detailed_errors` option to make them carry the violated bound and the actual value (or length):
+//!
+//! ```ignore
+//! #[nutype(
+//!     detailed_errors
+//!     validate(max_len = 32)
+//! )]
+//! pub struct Username(String);
+//!
+//! assert_eq!(
+//!     Username::new("a".repeat(40)),
+//!     Err(UsernameError::TooLong { max: 32, actual: 40 })
+//! );
+//! assert_eq!(
+//!     Username::new("a".repeat(40)).unwrap_err().to_string(),
+//!     "too long: expected at most 32 bytes, got 40"
+//! );
+//! ```
+//!
+//! Note, that detailed errors of float-based types do not implement `Eq`.
+//!
+//! ## Sanitizing and validating without constructing
+//!
+//! Every nutype exposes associated functions, that do the same steps as `new()`:
+//! * `sanitize(inner) -> inner` - applies the sanitizers.
+//! * `validate(inner) -> Result<(), Error>` - sanitizes the value and checks it against the validators, exactly like `new()` does.
+//! * `is_valid(inner) -> bool` - same as `validate()`, but only tells whether the value is valid.
+//!
+//! They allow to check a candidate value (e.g. during live form input) without constructing the type:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(trim)
+//!     validate(not_empty, max_len = 20)
+//! )]
+//! pub struct Username(String);
+//!
+//! assert_eq!(Username::sanitize("  Alice ".to_string()), "Alice");
+//! assert_eq!(Username::validate("   "), Err(UsernameError::Empty));
+//! assert!(Username::is_valid("Alice"));
+//! ```
+//!
+//! A type without validation is always valid, its `validate()` returns `Result<(), Infallible>`.
+//!
+//! ## Collecting errors
+//!
+//! `new()` stops at the first violated validation rule.
+//! If you need all of them at once (e.g. to validate a form), add the `collect_errors` option.
+//! It generates one more constructor, `new_collecting()`, which returns a vector of errors:
+//!
+//! ```ignore
+//! #[nutype(
+//!     collect_errors
+//!     validate(not_empty, min_len = 3, with = |s| s.is_ascii())
+//! )]
+//! pub struct Login(String);
+//!
+//! assert_eq!(
+//!     Login::new_collecting(""),
+//!     Err(vec![LoginError::Empty, LoginError::TooShort])
+//! );
+//! ```
+//!
+//! `collect_errors` can be combined with `detailed_errors`.
+//!
+//! ## Unchecked constructor
+//!
+//! If values come from a trusted source (e.g. your own database), sanitization and validation can be skipped with the `new_unchecked` option.
+//! It generates `unsafe fn new_unchecked()`:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     new_unchecked
+//!     sanitize(trim)
+//!     validate(not_empty, max_len = 20)
+//! )]
+//! pub struct Name(String);
+//!
+//! let name = unsafe { Name::new_unchecked("Anonymous") };
+//! ```
+//!
+//! It's the responsibility of the caller to guarantee that the value is sanitized and valid, otherwise the guarantees of the type do not hold.
+//! To catch mistakes early, the validation still runs in debug builds and `new_unchecked()` panics on an invalid value.
+//!
+//! ## Custom error type
+//!
+//! Instead of generating an error type, nutype can use your own one.
+//! Set it with the `error` option and use `with` validators that return `Result<(), YourError>`:
+//!
+//! ```ignore
+//! #[derive(Debug, PartialEq)]
+//! pub enum DomainError {
+//!     InvalidEmail,
+//! }
+//!
+//! fn validate_email(email: &str) -> Result<(), DomainError> {
+//!     if email.contains('@') {
+//!         Ok(())
+//!     } else {
+//!         Err(DomainError::InvalidEmail)
+//!     }
+//! }
+//!
+//! #[nutype(
+//!     error = DomainError
+//!     validate(with = validate_email)
+//! )]
+//! pub struct Email(String);
+//!
+//! assert_eq!(Email::new("foo"), Err(DomainError::InvalidEmail));
+//! ```
+//!
+//! `new()`, `TryFrom`, `FromStr` and `Deserialize` return the custom error type directly, so there is no need to convert it.
+//! Note, that the built-in validators (e.g. `max_len`) can not be used with a custom error type, because they report variants of the generated error.
+//! To derive `FromStr` or `Deserialize` the custom error type has to implement `Display`.
+//!
+//! ## Custom error messages
+//!
+//! The `Display` implementation of the generated error uses short messages like "too long" or "invalid".
+//! Every validator can have its own message instead. Use the parenthesized form of a validator and set `message`:
+//!
+//! ```ignore
+//! #[nutype(validate(
+//!     not_empty(message = "username is required"),
+//!     max_len(32, message = "username must be at most 32 characters"),
+//! ))]
+//! pub struct Username(String);
+//!
+//! assert_eq!(
+//!     Username::new("").unwrap_err().to_string(),
+//!     "username is required"
+//! );
+//! ```
+//!
+//! The message is also surfaced through the errors of derived `FromStr` and `Deserialize`.
+//!
+//! ## Default
+//!
+//! `Default` can be derived only together with the `default` option, that sets the default value:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(min = 18, max = 99)
+//!     default = 18
+//! )]
+//! #[derive(Default)]
+//! pub struct Age(u8);
+//!
+//! assert_eq!(Age::default().into_inner(), 18);
+//! ```
+//!
+//! The default value goes through the sanitizers and validators like any other value.
+//! Literals (e.g. `18` or `"Anonymous"`) are checked against the built-in validators at compile time.
+//! Other expressions can be verified only at runtime: `default()` panics, if the default value is invalid.
+//!
+//! ## Arithmetic
+//!
+//! Integer and float based types can derive `Add`, `Sub`, `Mul`, `Div`, `Neg` and `Sum`.
+//! The result of an operation goes through `new()`, so for a type with validation the output is `Result<Self, Error>`:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(min = 1, max = 100))]
+//! #[derive(Debug, PartialEq, Add)]
+//! pub struct Amount(u32);
+//!
+//! let a = Amount::new(40).unwrap();
+//! let b = Amount::new(70).unwrap();
+//! assert_eq!(a + b, Err(AmountError::TooBig));
+//! ```
+//!
+//! Deriving `Add`, `Sub`, `Mul` or `Div` also generates the corresponding methods:
+//! * `checked_add()` - returns `None` if the operation overflows or the result is invalid.
+//! * `saturating_add()` - clamps the result to the declared `min` and `max`.
+//!   It's generated only if clamping makes any value valid, i.e. there are no sanitizers and no validators other than `min`, `max` (and `finite` for floats).
+//!   Float types with `finite` or `not_nan` get no `saturating_div()`, because `0.0 / 0.0` is NaN, which can't be clamped.
+//!
+//! On overflow the integer operators and `Sum` panic, in release builds as well, so a wrapped value never gets into the type.
+//! Use `checked_*()` methods to handle overflow.
+//!
+//! ## Constants
+//!
+//! Integer and float based types get `new_const()`, a `const fn` that allows to define constants.
+//! The value is validated at compile time, so an invalid literal fails the compilation:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(min = 1024, max = 49151))]
+//! pub struct Port(u16);
+//!
+//! const HTTP_ALT: Port = Port::new_const(8080);
+//!
+//! // error[E0080]: evaluation panicked: `Port::new_const()` received a value, that violates `min` validator.
+//! // const HTTP: Port = Port::new_const(80);
+//! ```
+//!
+//! `new_const()` is generated only if the type has no sanitizers and the validators are built-in ones that can be evaluated in a const context: all of them, except `with` and `multiple_of` for floats.
+//! Validators are never skipped: a type with any other validators gets no `new_const()` at all, so it never accepts a value, that `new()` rejects.
+//! Outside of a const context `new_const()` panics on an invalid value, so prefer `new()` for values known only at runtime.
+//!
+//! ## Nutype trait
+//!
+//! Every nutype implements the `Nutype` trait, so generic code (e.g. repositories, forms or serialization helpers) can work with any of them:
+//!
+//! ```ignore
+//! use nutype::{nutype, Nutype};
+//!
+//! #[nutype(validate(not_empty))]
+//! pub struct Title(String);
+//!
+//! fn parse_all<T: Nutype>(values: Vec<T::Inner>) -> Result<Vec<T>, T::Error> {
+//!     values.into_iter().map(T::new).collect()
+//! }
+//!
+//! let titles: Vec<Title> = parse_all(vec!["Dune".to_string()]).unwrap();
+//! assert_eq!(titles[0].as_inner(), "Dune");
+//! ```
+//!
+//! The trait provides the associated types `Inner` and `Error`, and the methods `new()`, `into_inner()` and `as_inner()`.
+//! `new()` always returns `Result`: a type without validation never fails, so its `Error` is `Infallible`.
+//!
+//! ## Constraints
+//!
+//! Every nutype has `CONSTRAINTS` associated constant, that describes the inner type, the sanitizers and the validators as plain data.
+//! It allows tooling (e.g. generators of client-side validation or docs) to read the rules without parsing the source code:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//! use nutype::constraints::{Rule, Value};
+//!
+//! #[nutype(sanitize(trim) validate(not_empty, max_len = 20))]
+//! pub struct Username(String);
+//!
+//! assert_eq!(Username::CONSTRAINTS.inner_type, "String");
+//! assert_eq!(Username::CONSTRAINTS.sanitizers, &[Rule { name: "trim", args: &[] }]);
+//! assert_eq!(
+//!     Username::CONSTRAINTS.validator("max_len"),
+//!     Some(&Rule { name: "max_len", args: &[Value::UInt(20)] })
+//! );
+//! ```
+//!
+//! Rules are named as in `#[nutype(...)]`. Arguments of signed integer types are `Value::Int`, of unsigned integer types and lengths are `Value::UInt`, of float types are `Value::Float`, and regex patterns are `Value::Str`.
+//! Custom sanitizers and validators are named `with`; a named custom validator has its name as the argument, e.g. `[Value::Str("NotReserved")]`.
+//!
+//! ## JSON Schema
+//!
+//! With `schemars` feature enabled, nutypes can derive `JsonSchema` of [schemars](https://crates.io/crates/schemars) 0.8.
+//! The schema of the inner type is extended with the doc comment and the validation rules, so e.g. OpenAPI docs show the limits:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! /// Name of a user.
+//! #[nutype(validate(not_empty, char_len_max = 20))]
+//! #[derive(JsonSchema)]
+//! pub struct Username(String);
+//!
+//! // {"type": "string", "description": "Name of a user.", "minLength": 1, "maxLength": 20, ...}
+//! let schema = schemars::schema_for!(Username);
+//! ```
+//!
+//! | Validator                          | JSON Schema                            |
+//! |------------------------------------|----------------------------------------|
+//! | `not_empty`                        | `minLength: 1`                         |
+//! | `char_len_min`, `grapheme_len_min` | `minLength`                            |
+//! | `char_len_max`, `max_len`          | `maxLength`                            |
+//! | `regex`                            | `pattern`                              |
+//! | `min`, `max`                       | `minimum`, `maximum`                   |
+//! | `greater`, `less`                  | `exclusiveMinimum`, `exclusiveMaximum` |
+//! | `multiple_of`                      | `multipleOf`                           |
+//!
+//! JSON Schema counts length in chars, so the length limits that can't be expressed without rejecting valid values (`min_len` counts bytes, `grapheme_len_max` counts graphemes) are left out, as well as custom validators.
+//!
+//! ## sqlx
+//!
+//! With `sqlx` feature enabled, nutypes can derive `SqlxType`, which implements `Type`, `Encode` and `Decode` traits of [sqlx](https://crates.io/crates/sqlx) 0.8 for every database, that supports the inner type.
+//! Values are encoded as the inner type. Decoded values are sanitized and validated, so a row that violates the rules results in a decode error:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(sanitize(trim) validate(not_empty))]
+//! #[derive(SqlxType)]
+//! pub struct Username(String);
+//!
+//! let username: Username = sqlx::query_scalar("SELECT name FROM users WHERE id = ?")
+//!     .bind(42)
+//!     .fetch_one(&pool)
+//!     .await?;
+//!
+//! // If the name is blank, the error is:
+//! // error occurred while decoding column 0: Failed to decode Username: empty
+//! ```
 //!
 //! ## Feature flags
 //!
 //! * `serde1` - integrations with [`serde`](https://crates.io/crates/serde) crate. Allows to derive `Serialize` and `Deserialize` traits.
+//! * `schemars` - integration with [`schemars`](https://crates.io/crates/schemars) crate. Allows to derive `JsonSchema` trait. Note: your crate also has to explicitly have `schemars` 0.8 within its dependencies.
+//! * `sqlx` - integration with [`sqlx`](https://crates.io/crates/sqlx) crate. Allows to derive `SqlxType`, that implements `Type`, `Encode` and `Decode` traits. Note: your crate also has to explicitly have `sqlx` 0.8 within its dependencies.
+//! * `regex` - allows to use `regex = ` validation on string-based types. Note: your crate also has to explicitly have `regex` within its dependencies.
+//! * `graphemes` - allows to use `grapheme_len_min` and `grapheme_len_max` validation on string-based types. Note: your crate also has to explicitly have [`unicode-segmentation`](https://crates.io/crates/unicode-segmentation) within its dependencies.
 //!
 //! ## Support Ukrainian military forces 🇺🇦
 //!
@@ -256,8 +671,32 @@
 //!
 //! Thank you.
 
+pub mod constraints;
+
 pub use nutype_macros::nutype;
 
+// Allows the generated code to refer to `::nutype` within this crate as well (e.g. in tests).
+extern crate self as nutype;
+
+/// Implemented by every type defined with `#[nutype]`, so generic code can work with any of them.
+pub trait Nutype: Sized {
+    /// The wrapped type, e.g. `String` for `struct Username(String)`.
+    type Inner;
+
+    /// The error returned by `new()`. A type without validation never fails,
+    /// so it uses [`Infallible`](core::convert::Infallible).
+    type Error;
+
+    /// Sanitizes and validates the value, same as the inherent `new()`.
+    fn new(inner: Self::Inner) -> Result<Self, Self::Error>;
+
+    /// Returns the inner value.
+    fn into_inner(self) -> Self::Inner;
+
+    /// Returns a reference to the inner value.
+    fn as_inner(&self) -> &Self::Inner;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
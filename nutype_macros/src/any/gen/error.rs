@@ -0,0 +1,91 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use super::super::models::AnyValidator;
+use crate::common::{
+    gen::error::{gen_error_type_name, gen_impl_error_trait, gen_named_validator_message},
+    models::{ErrorOptions, NamedCustomValidator, TypeName},
+};
+
+pub fn gen_validation_error_type(
+    type_name: &TypeName,
+    validators: &[AnyValidator],
+    error_options: &ErrorOptions,
+) -> TokenStream {
+    let error_type_name = gen_error_type_name(type_name);
+    let definition = gen_definition(&error_type_name, validators);
+    let impl_display_trait = gen_impl_display_trait(&error_type_name, validators, error_options);
+    let impl_error_trait = gen_impl_error_trait(&error_type_name);
+
+    quote! {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #definition
+
+        #impl_display_trait
+        #impl_error_trait
+    }
+}
+
+fn gen_definition(error_type_name: &Ident, validators: &[AnyValidator]) -> TokenStream {
+    let error_variants: TokenStream = validators
+        .iter()
+        .map(|validator| match validator {
+            AnyValidator::With(_) => {
+                quote!(Invalid,)
+            }
+            AnyValidator::NamedWith(NamedCustomValidator { name, .. }) => {
+                quote!(#name,)
+            }
+        })
+        .collect();
+
+    quote! {
+        pub enum #error_type_name {
+            #error_variants
+        }
+    }
+}
+
+fn gen_impl_display_trait(
+    error_type_name: &Ident,
+    validators: &[AnyValidator],
+    error_options: &ErrorOptions,
+) -> TokenStream {
+    let match_arms = validators.iter().map(|validator| {
+        if let Some(message) = error_options.message_for(validator) {
+            let variant = error_variant_name(validator);
+            return quote! {
+                #error_type_name::#variant => f.write_str(#message)
+            };
+        }
+
+        match validator {
+            AnyValidator::With(_) => quote! {
+                 #error_type_name::Invalid => write!(f, "invalid")
+            },
+            AnyValidator::NamedWith(NamedCustomValidator { name, .. }) => {
+                let message = gen_named_validator_message(name);
+                quote! {
+                     #error_type_name::#name => f.write_str(#message)
+                }
+            }
+        }
+    });
+
+    quote! {
+        impl ::core::fmt::Display for #error_type_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(#match_arms,)*
+                }
+            }
+        }
+    }
+}
+
+pub fn error_variant_name(validator: &AnyValidator) -> Ident {
+    match validator {
+        AnyValidator::With(_) => Ident::new("Invalid", Span::call_site()),
+        AnyValidator::NamedWith(NamedCustomValidator { name, .. }) => name.clone(),
+    }
+}
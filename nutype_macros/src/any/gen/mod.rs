@@ -0,0 +1,384 @@
+pub mod error;
+pub mod traits;
+
+use std::collections::HashSet;
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Generics, Visibility};
+
+use self::{error::gen_validation_error_type, traits::gen_traits};
+use super::models::{AnyDeriveTrait, AnyGuard, AnySanitizer, AnyValidator};
+use crate::{
+    common::gen::{
+        error::{gen_error_type_name, gen_error_type_path},
+        gen_constraint_rule, gen_custom_validator_check, gen_impl_constraints,
+        gen_impl_nutype_trait, gen_module_name_for_type, gen_named_custom_validator_check,
+        gen_new_unchecked, gen_reimports, gen_sanitize_and_validate_fns,
+        gen_validate_fn_from_checks,
+        parse_error::gen_parse_error_name,
+        traits::{gen_json_schema_refinements, GeneratedTraits},
+        type_custom_closure, ConstraintValue,
+    },
+    common::models::{Attributes, ErrorOptions, NamedCustomValidator, TypeName},
+};
+
+pub fn gen_nutype_for_any(
+    doc_attrs: Vec<syn::Attribute>,
+    vis: Visibility,
+    inner_type: &syn::Type,
+    type_name: &TypeName,
+    generics: &Generics,
+    attributes: Attributes<AnyGuard>,
+    traits: HashSet<AnyDeriveTrait>,
+) -> TokenStream {
+    let Attributes {
+        guard,
+        error_options,
+        default,
+        new_unchecked,
+    } = attributes;
+    let module_name = gen_module_name_for_type(type_name);
+    let implementation = gen_implementation(
+        type_name,
+        inner_type,
+        generics,
+        &guard,
+        &error_options,
+        new_unchecked,
+    );
+    let inner_type: TokenStream = quote!(#inner_type);
+
+    let maybe_error_type_name: Option<syn::Path> = match guard {
+        AnyGuard::WithoutValidation { .. } => None,
+        AnyGuard::WithValidation { .. } => Some(gen_error_type_path(type_name, &error_options)),
+    };
+    let impl_nutype_trait = gen_impl_nutype_trait(
+        type_name,
+        &inner_type,
+        generics,
+        maybe_error_type_name.as_ref(),
+    );
+
+    // A custom error type is defined by the user, so only the generated one needs to be reimported.
+    let maybe_reimported_error_type_name: Option<Ident> = match guard {
+        AnyGuard::WithValidation { .. } if error_options.custom_type.is_none() => {
+            Some(gen_error_type_name(type_name))
+        }
+        _ => None,
+    };
+
+    let maybe_parse_error_type_name = if traits.contains(&AnyDeriveTrait::FromStr) {
+        Some(gen_parse_error_name(type_name))
+    } else {
+        None
+    };
+
+    let reimports = gen_reimports(
+        vis,
+        type_name,
+        &module_name,
+        maybe_reimported_error_type_name.as_ref(),
+        maybe_parse_error_type_name.as_ref(),
+    );
+
+    let GeneratedTraits {
+        derive_standard_traits,
+        implement_traits,
+    } = gen_traits(
+        type_name,
+        generics,
+        &inner_type,
+        maybe_error_type_name,
+        default,
+        gen_json_schema_refinements(&doc_attrs, quote!()),
+        traits,
+    );
+    let where_clause = &generics.where_clause;
+
+    quote!(
+        #[doc(hidden)]
+        mod #module_name {
+            use super::*;
+
+            #(#doc_attrs)*
+            #derive_standard_traits
+            pub struct #type_name #generics (#inner_type) #where_clause;
+
+            #implementation
+            #impl_nutype_trait
+            #implement_traits
+        }
+        #reimports
+    )
+}
+
+pub fn gen_implementation(
+    type_name: &TypeName,
+    inner_type: &syn::Type,
+    generics: &Generics,
+    guard: &AnyGuard,
+    error_options: &ErrorOptions,
+    new_unchecked: bool,
+) -> TokenStream {
+    let convert_implementation = match guard {
+        AnyGuard::WithoutValidation { sanitizers } => {
+            gen_new_without_validation(type_name, inner_type, generics, sanitizers, new_unchecked)
+        }
+        AnyGuard::WithValidation {
+            sanitizers,
+            validators,
+        } => gen_new_with_validation(
+            type_name,
+            inner_type,
+            generics,
+            sanitizers,
+            validators,
+            error_options,
+            new_unchecked,
+        ),
+    };
+    let constraints = gen_constraints(type_name, inner_type, generics, guard);
+    let methods = gen_impl_methods(type_name, inner_type, generics);
+
+    quote! {
+        #convert_implementation
+        #constraints
+        #methods
+    }
+}
+
+fn gen_constraints(
+    type_name: &TypeName,
+    inner_type: &syn::Type,
+    generics: &Generics,
+    guard: &AnyGuard,
+) -> TokenStream {
+    let sanitizers = guard
+        .sanitizers()
+        .iter()
+        .map(|sanitizer| match sanitizer {
+            AnySanitizer::With(_) => gen_constraint_rule("with", &[]),
+        })
+        .collect();
+    let validators = guard
+        .validators()
+        .iter()
+        .map(|validator| match validator {
+            AnyValidator::With(_) => gen_constraint_rule("with", &[]),
+            AnyValidator::NamedWith(NamedCustomValidator { name, .. }) => {
+                gen_constraint_rule("with", &[ConstraintValue::Str.gen(name.to_string())])
+            }
+        })
+        .collect();
+    gen_impl_constraints(type_name, inner_type, generics, sanitizers, validators)
+}
+
+fn gen_impl_methods(
+    type_name: &TypeName,
+    inner_type: &syn::Type,
+    generics: &Generics,
+) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #type_name #type_generics #where_clause {
+            pub fn into_inner(self) -> #inner_type {
+                self.0
+            }
+        }
+    }
+}
+
+fn gen_new_without_validation(
+    type_name: &TypeName,
+    inner_type: &syn::Type,
+    generics: &Generics,
+    sanitizers: &[AnySanitizer],
+    new_unchecked: bool,
+) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let sanitize = gen_sanitize_fn(inner_type, generics, sanitizers);
+    let new_unchecked = if new_unchecked {
+        gen_new_unchecked(
+            type_name,
+            quote!(raw_value: #inner_type),
+            quote!(raw_value),
+            false,
+        )
+    } else {
+        quote!()
+    };
+
+    let sanitize_and_validate = gen_sanitize_and_validate_fns(
+        quote!(#inner_type),
+        quote!(raw_value: #inner_type),
+        quote!(raw_value),
+        quote!(&#inner_type),
+        sanitize,
+        None,
+    );
+
+    quote!(
+        impl #impl_generics #type_name #type_generics #where_clause {
+            pub fn new(raw_value: #inner_type) -> Self {
+                Self(Self::sanitize(raw_value))
+            }
+
+            #sanitize_and_validate
+
+            #new_unchecked
+        }
+    )
+}
+
+fn gen_new_with_validation(
+    type_name: &TypeName,
+    inner_type: &syn::Type,
+    generics: &Generics,
+    sanitizers: &[AnySanitizer],
+    validators: &[AnyValidator],
+    error_options: &ErrorOptions,
+    new_unchecked: bool,
+) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let sanitize = gen_sanitize_fn(inner_type, generics, sanitizers);
+    let validation_error = if error_options.custom_type.is_none() {
+        gen_validation_error_type(type_name, validators, error_options)
+    } else {
+        quote!()
+    };
+    let error_type_name = gen_error_type_path(type_name, error_options);
+    let validate = gen_validate_fn(
+        type_name,
+        inner_type,
+        generics,
+        validators,
+        error_options,
+        false,
+    );
+
+    let new_collecting = if error_options.collect {
+        let validate_all = gen_validate_fn(
+            type_name,
+            inner_type,
+            generics,
+            validators,
+            error_options,
+            true,
+        );
+        quote!(
+            /// Same as `new()`, but instead of stopping at the first error, returns all the violated validation rules.
+            pub fn new_collecting(raw_value: #inner_type) -> ::core::result::Result<Self, ::std::vec::Vec<#error_type_name>> {
+                #validate_all
+
+                let sanitized_value = Self::sanitize(raw_value);
+                validate_all(&sanitized_value)?;
+                Ok(#type_name(sanitized_value))
+            }
+        )
+    } else {
+        quote!()
+    };
+
+    let new_unchecked = if new_unchecked {
+        gen_new_unchecked(
+            type_name,
+            quote!(raw_value: #inner_type),
+            quote!(raw_value),
+            true,
+        )
+    } else {
+        quote!()
+    };
+
+    let sanitize_and_validate = gen_sanitize_and_validate_fns(
+        quote!(#inner_type),
+        quote!(raw_value: #inner_type),
+        quote!(raw_value),
+        quote!(&#inner_type),
+        sanitize,
+        Some((&error_type_name, quote!(#validate validate(value)))),
+    );
+
+    quote!(
+        #validation_error
+
+        impl #impl_generics #type_name #type_generics #where_clause {
+            pub fn new(raw_value: #inner_type) -> ::core::result::Result<Self, #error_type_name> {
+                let sanitized_value = Self::sanitize(raw_value);
+                Self::validate_sanitized(&sanitized_value)?;
+                Ok(#type_name(sanitized_value))
+            }
+
+            #sanitize_and_validate
+
+            #new_collecting
+            #new_unchecked
+        }
+    )
+}
+
+fn gen_sanitize_fn(
+    inner_type: &syn::Type,
+    generics: &Generics,
+    sanitizers: &[AnySanitizer],
+) -> TokenStream {
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let transformations: TokenStream = sanitizers
+        .iter()
+        .map(|san| match san {
+            AnySanitizer::With(token_stream) => {
+                let custom_sanitizer = type_custom_closure(token_stream, inner_type);
+                quote!(
+                    value = (#custom_sanitizer)(value);
+                )
+            }
+        })
+        .collect();
+
+    quote!(
+        fn sanitize #impl_generics (mut value: #inner_type) -> #inner_type #where_clause {
+            #transformations
+            value
+        }
+    )
+}
+
+/// Unlike the other inner types, the value is validated by reference,
+/// because the inner type is not necessarily `Copy`.
+fn gen_validate_fn(
+    type_name: &TypeName,
+    inner_type: &syn::Type,
+    generics: &Generics,
+    validators: &[AnyValidator],
+    error_options: &ErrorOptions,
+    collect: bool,
+) -> TokenStream {
+    let error_name = &gen_error_type_path(type_name, error_options);
+    let validations: TokenStream = validators
+        .iter()
+        .map(|validator| match validator {
+            AnyValidator::With(is_valid_fn) => {
+                let is_valid_fn = type_custom_closure(is_valid_fn, quote!(&#inner_type));
+                gen_custom_validator_check(is_valid_fn, error_name, error_options, collect)
+            }
+            AnyValidator::NamedWith(NamedCustomValidator { name, func }) => {
+                let is_valid_fn = type_custom_closure(func, quote!(&#inner_type));
+                gen_named_custom_validator_check(is_valid_fn, error_name, name, collect)
+            }
+        })
+        .collect();
+
+    let validate_fn = gen_validate_fn_from_checks(
+        quote!(val: &#inner_type),
+        generics,
+        error_name,
+        validations,
+        collect,
+    );
+    // The inner type is given by the user, it may be e.g. `Vec<T>` or `Cow<str>`.
+    quote!(
+        #[allow(clippy::ptr_arg)]
+        #validate_fn
+    )
+}
@@ -0,0 +1,225 @@
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Generics};
+
+use crate::{
+    any::models::AnyDeriveTrait,
+    common::{
+        gen::traits::{
+            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
+            gen_impl_trait_deref, gen_impl_trait_dislpay, gen_impl_trait_from,
+            gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_schemars_json_schema,
+            gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
+            gen_impl_trait_sqlx_type, gen_impl_trait_try_from, split_into_generatable_traits,
+            GeneratableTrait, GeneratableTraits, GeneratedTraits,
+        },
+        models::TypeName,
+    },
+};
+
+type AnyGeneratableTrait = GeneratableTrait<AnyStandardTrait, AnyIrregularTrait>;
+
+pub fn gen_traits(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &TokenStream,
+    maybe_error_type_name: Option<syn::Path>,
+    maybe_default_value: Option<syn::Expr>,
+    schema_refinements: TokenStream,
+    traits: HashSet<AnyDeriveTrait>,
+) -> GeneratedTraits {
+    let GeneratableTraits {
+        standard_traits,
+        irregular_traits,
+    } = split_into_generatable_traits(traits);
+
+    let derive_standard_traits = quote! {
+        #[derive(
+            #(#standard_traits,)*
+        )]
+    };
+
+    let implement_traits = gen_implemented_traits(
+        type_name,
+        generics,
+        inner_type,
+        maybe_error_type_name.as_ref(),
+        maybe_default_value.as_ref(),
+        schema_refinements,
+        irregular_traits,
+    );
+
+    GeneratedTraits {
+        derive_standard_traits,
+        implement_traits,
+    }
+}
+
+impl From<AnyDeriveTrait> for AnyGeneratableTrait {
+    fn from(derive_trait: AnyDeriveTrait) -> AnyGeneratableTrait {
+        match derive_trait {
+            AnyDeriveTrait::Debug => AnyGeneratableTrait::Standard(AnyStandardTrait::Debug),
+            AnyDeriveTrait::Clone => AnyGeneratableTrait::Standard(AnyStandardTrait::Clone),
+            AnyDeriveTrait::Copy => AnyGeneratableTrait::Standard(AnyStandardTrait::Copy),
+            AnyDeriveTrait::PartialEq => AnyGeneratableTrait::Standard(AnyStandardTrait::PartialEq),
+            AnyDeriveTrait::Eq => AnyGeneratableTrait::Standard(AnyStandardTrait::Eq),
+            AnyDeriveTrait::PartialOrd => {
+                AnyGeneratableTrait::Standard(AnyStandardTrait::PartialOrd)
+            }
+            AnyDeriveTrait::Ord => AnyGeneratableTrait::Standard(AnyStandardTrait::Ord),
+            AnyDeriveTrait::Hash => AnyGeneratableTrait::Standard(AnyStandardTrait::Hash),
+            AnyDeriveTrait::FromStr => AnyGeneratableTrait::Irregular(AnyIrregularTrait::FromStr),
+            AnyDeriveTrait::AsRef => AnyGeneratableTrait::Irregular(AnyIrregularTrait::AsRef),
+            AnyDeriveTrait::Deref => AnyGeneratableTrait::Irregular(AnyIrregularTrait::Deref),
+            AnyDeriveTrait::Into => AnyGeneratableTrait::Irregular(AnyIrregularTrait::Into),
+            AnyDeriveTrait::From => AnyGeneratableTrait::Irregular(AnyIrregularTrait::From),
+            AnyDeriveTrait::TryFrom => AnyGeneratableTrait::Irregular(AnyIrregularTrait::TryFrom),
+            AnyDeriveTrait::Borrow => AnyGeneratableTrait::Irregular(AnyIrregularTrait::Borrow),
+            AnyDeriveTrait::Default => AnyGeneratableTrait::Irregular(AnyIrregularTrait::Default),
+            AnyDeriveTrait::Display => AnyGeneratableTrait::Irregular(AnyIrregularTrait::Display),
+            AnyDeriveTrait::SerdeSerialize => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::SerdeSerialize)
+            }
+            AnyDeriveTrait::SerdeDeserialize => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::SerdeDeserialize)
+            }
+            AnyDeriveTrait::SchemarsJsonSchema => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::SchemarsJsonSchema)
+            }
+            AnyDeriveTrait::SqlxType => AnyGeneratableTrait::Irregular(AnyIrregularTrait::SqlxType),
+        }
+    }
+}
+
+/// A trait that can be automatically derived.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum AnyStandardTrait {
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+}
+
+/// A trait that can not be automatically derived and we need to generate
+/// an implementation for it.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum AnyIrregularTrait {
+    FromStr,
+    AsRef,
+    Deref,
+    From,
+    TryFrom,
+    Borrow,
+    Into,
+    Display,
+    Default,
+    SerdeSerialize,
+    SerdeDeserialize,
+    SchemarsJsonSchema,
+    SqlxType,
+}
+
+impl ToTokens for AnyStandardTrait {
+    fn to_tokens(&self, token_stream: &mut TokenStream) {
+        let tokens = match self {
+            Self::Debug => quote!(Debug),
+            Self::Clone => quote!(Clone),
+            Self::Copy => quote!(Copy),
+            Self::PartialEq => quote!(PartialEq),
+            Self::Eq => quote!(Eq),
+            Self::PartialOrd => quote!(PartialOrd),
+            Self::Ord => quote!(Ord),
+            Self::Hash => quote!(Hash),
+        };
+        tokens.to_tokens(token_stream)
+    }
+}
+
+/// Unlike the other inner types, the implementations are generated with respect to generic
+/// parameters of the type, so the inner type gets bounds of the traits it has to implement
+/// (e.g. `Vec<T>: Display`). `FromStr` can not be derived for generic types.
+fn gen_implemented_traits(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: &TokenStream,
+    maybe_error_type_name: Option<&syn::Path>,
+    maybe_default_value: Option<&syn::Expr>,
+    schema_refinements: TokenStream,
+    impl_traits: Vec<AnyIrregularTrait>,
+) -> TokenStream {
+    impl_traits
+        .iter()
+        .map(|t| match t {
+            AnyIrregularTrait::AsRef => gen_impl_trait_as_ref(type_name, generics, inner_type),
+            AnyIrregularTrait::Deref => gen_impl_trait_deref(type_name, generics, inner_type),
+            AnyIrregularTrait::FromStr => {
+                gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name)
+            }
+            AnyIrregularTrait::From => gen_impl_trait_from(type_name, generics, inner_type),
+            AnyIrregularTrait::Into => gen_impl_trait_into(type_name, generics, inner_type),
+            // `TryFrom` is derived only when there is validation, hence an error type.
+            AnyIrregularTrait::TryFrom => maybe_error_type_name
+                .map(|error_type_name| {
+                    gen_impl_trait_try_from(type_name, generics, inner_type, error_type_name)
+                })
+                .unwrap_or_default(),
+            AnyIrregularTrait::Borrow => gen_impl_trait_borrow(type_name, generics, inner_type),
+            // `Default` is derived only together with `default = ...`.
+            AnyIrregularTrait::Default => maybe_default_value
+                .map(|default_value| {
+                    gen_impl_trait_default(
+                        type_name,
+                        generics,
+                        default_value,
+                        maybe_error_type_name.is_some(),
+                    )
+                })
+                .unwrap_or_default(),
+            AnyIrregularTrait::Display => {
+                let generics = add_bound(generics, quote!(#inner_type: ::core::fmt::Display));
+                gen_impl_trait_dislpay(type_name, &generics)
+            }
+            AnyIrregularTrait::SerdeSerialize => {
+                let generics = add_bound(generics, quote!(#inner_type: ::serde::Serialize));
+                gen_impl_trait_serde_serialize(type_name, &generics)
+            }
+            AnyIrregularTrait::SerdeDeserialize => {
+                let generics = add_bound(generics, quote!(#inner_type: ::serde::Deserialize<'de>));
+                gen_impl_trait_serde_deserialize(
+                    type_name,
+                    &generics,
+                    inner_type,
+                    maybe_error_type_name,
+                )
+            }
+            AnyIrregularTrait::SchemarsJsonSchema => {
+                let generics = add_bound(generics, quote!(#inner_type: ::schemars::JsonSchema));
+                gen_impl_trait_schemars_json_schema(
+                    type_name,
+                    &generics,
+                    inner_type,
+                    &schema_refinements,
+                )
+            }
+            AnyIrregularTrait::SqlxType => {
+                gen_impl_trait_sqlx_type(type_name, generics, inner_type, maybe_error_type_name)
+            }
+        })
+        .collect()
+}
+
+/// Adds a where-predicate to the generics, e.g. `Vec<T>: Display`.
+fn add_bound(generics: &Generics, predicate: TokenStream) -> Generics {
+    let mut generics = generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#predicate));
+    generics
+}
@@ -0,0 +1,4 @@
+pub mod gen;
+pub mod models;
+pub mod parse;
+pub mod validate;
@@ -0,0 +1,109 @@
+use proc_macro2::TokenStream;
+
+use crate::{
+    common::models::{Guard, RawGuard},
+    common::models::{Kind, NamedCustomValidator, SpannedItem},
+};
+
+// Sanitizer
+//
+
+#[derive(Debug)]
+pub enum AnySanitizer {
+    With(TokenStream),
+}
+
+pub type SpannedAnySanitizer = SpannedItem<AnySanitizer>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AnySanitizerKind {
+    With,
+}
+
+impl std::fmt::Display for AnySanitizerKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::With => write!(f, "with"),
+        }
+    }
+}
+
+impl Kind for AnySanitizer {
+    type Kind = AnySanitizerKind;
+
+    fn kind(&self) -> AnySanitizerKind {
+        match self {
+            Self::With(_) => AnySanitizerKind::With,
+        }
+    }
+}
+
+// Validator
+//
+
+#[derive(Debug)]
+pub enum AnyValidator {
+    With(TokenStream),
+    NamedWith(NamedCustomValidator),
+}
+
+pub type SpannedAnyValidator = SpannedItem<AnyValidator>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AnyValidatorKind {
+    With,
+    NamedWith(String),
+}
+
+impl std::fmt::Display for AnyValidatorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::With => write!(f, "with"),
+            Self::NamedWith(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl Kind for AnyValidator {
+    type Kind = AnyValidatorKind;
+
+    fn kind(&self) -> AnyValidatorKind {
+        match self {
+            Self::With(_) => AnyValidatorKind::With,
+            Self::NamedWith(validator) => AnyValidatorKind::NamedWith(validator.name.to_string()),
+        }
+    }
+}
+
+// Traits
+//
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum AnyDeriveTrait {
+    // Standard
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    FromStr,
+    AsRef,
+    Deref,
+    Into,
+    From,
+    TryFrom,
+    Hash,
+    Borrow,
+    Display,
+    Default,
+
+    // // External crates
+    SerdeSerialize,
+    SerdeDeserialize,
+    SchemarsJsonSchema,
+    SqlxType,
+}
+
+pub type AnyRawGuard = RawGuard<SpannedAnySanitizer, SpannedAnyValidator>;
+pub type AnyGuard = Guard<AnySanitizer, AnyValidator>;
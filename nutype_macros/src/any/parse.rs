@@ -0,0 +1,94 @@
+use crate::common::models::Attributes;
+use crate::common::parse::{
+    is_comma, parse_named_custom_validator, parse_nutype_attributes, parse_with_token_stream,
+    split_and_parse,
+};
+use crate::common::validate::validate_error_options;
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+
+use super::{
+    models::{
+        AnyGuard, AnyRawGuard, AnySanitizer, AnyValidator, SpannedAnySanitizer, SpannedAnyValidator,
+    },
+    validate::validate_any_meta,
+};
+
+pub fn parse_attributes(input: TokenStream) -> Result<Attributes<AnyGuard>, syn::Error> {
+    let raw_attributes = parse_raw_attributes(input)?;
+    validate_error_options(&raw_attributes, |v| matches!(v.item, AnyValidator::With(_)))?;
+    raw_attributes.try_map_guard(validate_any_meta)
+}
+
+fn parse_raw_attributes(input: TokenStream) -> Result<Attributes<AnyRawGuard>, syn::Error> {
+    parse_nutype_attributes(parse_sanitize_attrs, parse_validate_attrs)(input)
+}
+
+fn parse_sanitize_attrs(stream: TokenStream) -> Result<Vec<SpannedAnySanitizer>, syn::Error> {
+    let tokens: Vec<TokenTree> = stream.into_iter().collect();
+    split_and_parse(tokens, is_comma, parse_sanitize_attr)
+}
+
+fn parse_sanitize_attr(tokens: Vec<TokenTree>) -> Result<SpannedAnySanitizer, syn::Error> {
+    let mut token_iter = tokens.iter();
+    let token = token_iter.next();
+    if let Some(TokenTree::Ident(ident)) = token {
+        match ident.to_string().as_ref() {
+            "with" => {
+                // Preserve the rest as `custom_sanitizer_fn`
+                let stream = parse_with_token_stream(token_iter, ident.span())?;
+                let span = ident.span();
+                let sanitizer = AnySanitizer::With(stream);
+                Ok(SpannedAnySanitizer {
+                    span,
+                    item: sanitizer,
+                })
+            }
+            unknown_sanitizer => {
+                let msg = format!("Unknown sanitizer `{unknown_sanitizer}`.\nOnly custom sanitizers (`with = ...`) are available for this inner type.");
+                let error = syn::Error::new(ident.span(), msg);
+                Err(error)
+            }
+        }
+    } else {
+        Err(syn::Error::new(Span::call_site(), "Invalid syntax."))
+    }
+}
+
+fn parse_validate_attrs(stream: TokenStream) -> Result<Vec<SpannedAnyValidator>, syn::Error> {
+    let tokens: Vec<TokenTree> = stream.into_iter().collect();
+    split_and_parse(tokens, is_comma, parse_validate_attr)
+}
+
+fn parse_validate_attr(tokens: Vec<TokenTree>) -> Result<SpannedAnyValidator, syn::Error> {
+    let mut token_iter = tokens.into_iter();
+    let token = token_iter.next();
+    if let Some(TokenTree::Ident(ident)) = token {
+        match ident.to_string().as_ref() {
+            "with" => {
+                let rest_tokens: Vec<_> = token_iter.collect();
+                let validator = match rest_tokens.as_slice() {
+                    [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+                        let named = parse_named_custom_validator(group, ident.span())?;
+                        AnyValidator::NamedWith(named)
+                    }
+                    _ => {
+                        let stream = parse_with_token_stream(rest_tokens.iter(), ident.span())?;
+                        AnyValidator::With(stream)
+                    }
+                };
+                let span = ident.span();
+                Ok(SpannedAnyValidator {
+                    span,
+                    item: validator,
+                })
+            }
+            unknown_validator => {
+                let msg = format!("Unknown validation rule `{unknown_validator}`.\nOnly custom validators (`with = ...`) are available for this inner type.");
+                let error = syn::Error::new(ident.span(), msg);
+                Err(error)
+            }
+        }
+    } else {
+        Err(syn::Error::new(Span::call_site(), "Invalid syntax."))
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::HashSet;
+
+use proc_macro2::Span;
+use syn::Generics;
+
+use crate::{
+    common::models::{DeriveTrait, NamedCustomValidator, NormalDeriveTrait, SpannedDeriveTrait},
+    common::validate::{validate_duplicates, validate_error_variant_names},
+};
+
+use super::gen::error::error_variant_name;
+use super::models::{
+    AnyDeriveTrait, AnyGuard, AnyRawGuard, AnySanitizer, AnyValidator, SpannedAnySanitizer,
+    SpannedAnyValidator,
+};
+
+pub fn validate_any_meta(raw_meta: AnyRawGuard) -> Result<AnyGuard, syn::Error> {
+    let AnyRawGuard {
+        sanitizers,
+        validators,
+    } = raw_meta;
+
+    let validators = validate_validators(validators)?;
+    let sanitizers = validate_sanitizers(sanitizers)?;
+
+    if validators.is_empty() {
+        Ok(AnyGuard::WithoutValidation { sanitizers })
+    } else {
+        Ok(AnyGuard::WithValidation {
+            sanitizers,
+            validators,
+        })
+    }
+}
+
+fn validate_validators(
+    validators: Vec<SpannedAnyValidator>,
+) -> Result<Vec<AnyValidator>, syn::Error> {
+    validate_duplicates(&validators, |kind| {
+        format!("Duplicated validator `{kind}`.\nGive each custom validator a name, if you need more than one.")
+    })?;
+
+    validate_error_variant_names(&validators, error_variant_name, |v| match v {
+        AnyValidator::NamedWith(NamedCustomValidator { name, .. }) => Some(name),
+        _ => None,
+    })?;
+
+    let validators: Vec<_> = validators.into_iter().map(|v| v.item).collect();
+    Ok(validators)
+}
+
+fn validate_sanitizers(
+    sanitizers: Vec<SpannedAnySanitizer>,
+) -> Result<Vec<AnySanitizer>, syn::Error> {
+    validate_duplicates(&sanitizers, |kind| {
+        format!("Duplicated sanitizer `{kind}`.\nIt happens, don't worry. We still love you!")
+    })?;
+
+    let sanitizers: Vec<_> = sanitizers.into_iter().map(|s| s.item).collect();
+    Ok(sanitizers)
+}
+
+pub fn validate_any_derive_traits(
+    spanned_derive_traits: Vec<SpannedDeriveTrait>,
+    has_validation: bool,
+    generics: &Generics,
+) -> Result<HashSet<AnyDeriveTrait>, syn::Error> {
+    let is_generic = !generics.params.is_empty();
+
+    let mut traits = HashSet::with_capacity(24);
+
+    for spanned_trait in spanned_derive_traits {
+        match spanned_trait.item {
+            DeriveTrait::Asterisk => {
+                let msg = "#[nutype] cannot derive `*` for this inner type, because it does not know which traits the inner type implements.\nPlease, list the traits explicitly.";
+                return Err(syn::Error::new(spanned_trait.span, msg));
+            }
+            DeriveTrait::Normal(normal_trait) => {
+                if is_generic && normal_trait == NormalDeriveTrait::FromStr {
+                    let msg = "#[nutype] cannot derive `FromStr` for a generic type, because the parse error would depend on the generic parameters.";
+                    return Err(syn::Error::new(spanned_trait.span, msg));
+                }
+                let any_derive_trait =
+                    to_any_derive_trait(normal_trait, has_validation, spanned_trait.span)?;
+                traits.insert(any_derive_trait);
+            }
+        };
+    }
+
+    Ok(traits)
+}
+
+fn to_any_derive_trait(
+    tr: NormalDeriveTrait,
+    has_validation: bool,
+    span: Span,
+) -> Result<AnyDeriveTrait, syn::Error> {
+    match tr {
+        NormalDeriveTrait::Debug => Ok(AnyDeriveTrait::Debug),
+        NormalDeriveTrait::Display => Ok(AnyDeriveTrait::Display),
+        NormalDeriveTrait::Default => Ok(AnyDeriveTrait::Default),
+        NormalDeriveTrait::Clone => Ok(AnyDeriveTrait::Clone),
+        NormalDeriveTrait::Copy => Ok(AnyDeriveTrait::Copy),
+        NormalDeriveTrait::Add
+        | NormalDeriveTrait::Sub
+        | NormalDeriveTrait::Mul
+        | NormalDeriveTrait::Div
+        | NormalDeriveTrait::Neg
+        | NormalDeriveTrait::Sum => Err(syn::Error::new(
+            span,
+            format!("{tr:?} trait can be derived only for integer and float based types"),
+        )),
+        NormalDeriveTrait::PartialEq => Ok(AnyDeriveTrait::PartialEq),
+        NormalDeriveTrait::Eq => Ok(AnyDeriveTrait::Eq),
+        NormalDeriveTrait::PartialOrd => Ok(AnyDeriveTrait::PartialOrd),
+        NormalDeriveTrait::Ord => Ok(AnyDeriveTrait::Ord),
+        NormalDeriveTrait::Into => Ok(AnyDeriveTrait::Into),
+        NormalDeriveTrait::FromStr => Ok(AnyDeriveTrait::FromStr),
+        NormalDeriveTrait::AsRef => Ok(AnyDeriveTrait::AsRef),
+        NormalDeriveTrait::Deref => Ok(AnyDeriveTrait::Deref),
+        NormalDeriveTrait::Hash => Ok(AnyDeriveTrait::Hash),
+        NormalDeriveTrait::Borrow => Ok(AnyDeriveTrait::Borrow),
+        NormalDeriveTrait::SerdeSerialize => Ok(AnyDeriveTrait::SerdeSerialize),
+        NormalDeriveTrait::SerdeDeserialize => Ok(AnyDeriveTrait::SerdeDeserialize),
+        NormalDeriveTrait::SchemarsJsonSchema => Ok(AnyDeriveTrait::SchemarsJsonSchema),
+        NormalDeriveTrait::SqlxType => Ok(AnyDeriveTrait::SqlxType),
+        NormalDeriveTrait::From => {
+            if has_validation {
+                Err(syn::Error::new(span, "#[nutype] cannot derive `From` trait, because there is validation defined. Use `TryFrom` instead."))
+            } else {
+                Ok(AnyDeriveTrait::From)
+            }
+        }
+        NormalDeriveTrait::TryFrom => {
+            if has_validation {
+                Ok(AnyDeriveTrait::TryFrom)
+            } else {
+                Err(syn::Error::new(span, "#[nutype] cannot derive `TryFrom`, because there is no validation. Use `From` instead."))
+            }
+        }
+    }
+}
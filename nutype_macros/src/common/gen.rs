@@ -2,10 +2,10 @@ pub mod error;
 pub mod parse_error;
 pub mod traits;
 
-use super::models::TypeName;
+use super::models::{ErrorOptions, TypeName};
 use proc_macro2::{Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
-use syn::Visibility;
+use syn::{Generics, Visibility};
 
 /// Inject an inner type into a closure, so compiler does not complain if the token stream matchers
 /// the expected closure pattern.
@@ -46,6 +46,326 @@ fn is_ident(token: &TokenTree) -> bool {
     matches!(token, TokenTree::Ident(_))
 }
 
+/// Generates a statement that reports a violated validation rule:
+/// * returns the error right away, or
+/// * pushes the error into `errors`, if all the errors need to be collected (see `collect_errors`).
+pub fn gen_report_error(error: TokenStream, collect: bool) -> TokenStream {
+    if collect {
+        quote!(errors.push(#error);)
+    } else {
+        quote!(return Err(#error);)
+    }
+}
+
+/// Generates a check for a custom validator (`with = ...`) that is already typed with
+/// `type_custom_closure()`:
+/// * by default it's a predicate and the generated `Invalid` variant is reported;
+/// * with a custom error type (`error = ...`) it returns `Result<(), CustomError>` and the error
+///   is reported as it is.
+pub fn gen_custom_validator_check(
+    typed_custom_validator: TokenStream,
+    error_type_path: &syn::Path,
+    error_options: &ErrorOptions,
+    collect: bool,
+) -> TokenStream {
+    if error_options.custom_type.is_some() {
+        if collect {
+            quote!(
+                if let Err(error) = (#typed_custom_validator)(&val) {
+                    errors.push(error);
+                }
+            )
+        } else {
+            quote!(
+                (#typed_custom_validator)(&val)?;
+            )
+        }
+    } else {
+        let report = gen_report_error(quote!(#error_type_path::Invalid), collect);
+        quote!(
+            if !(#typed_custom_validator)(&val) {
+                #report
+            }
+        )
+    }
+}
+
+/// Generates a check for a named custom validator (`with(name = ..., fn = ...)`) that is already
+/// typed with `type_custom_closure()`. The variant with the validator's name is reported.
+pub fn gen_named_custom_validator_check(
+    typed_custom_validator: TokenStream,
+    error_type_path: &syn::Path,
+    name: &Ident,
+    collect: bool,
+) -> TokenStream {
+    let report = gen_report_error(quote!(#error_type_path::#name), collect);
+    quote!(
+        if !(#typed_custom_validator)(&val) {
+            #report
+        }
+    )
+}
+
+/// Wraps validation checks (see `gen_report_error()`) into a function:
+/// * `fn validate(val) -> Result<(), Error>`, that returns the first error, or
+/// * `fn validate_all(val) -> Result<(), Vec<Error>>`, that returns all the errors.
+///
+/// The function is nested into `new()`, so it has to declare generic parameters of the type on its
+/// own, if there are any.
+pub fn gen_validate_fn_from_checks(
+    val_param: TokenStream,
+    generics: &Generics,
+    error_type_name: &syn::Path,
+    checks: TokenStream,
+    collect: bool,
+) -> TokenStream {
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    if collect {
+        quote!(
+            fn validate_all #impl_generics (#val_param) -> ::core::result::Result<(), ::std::vec::Vec<#error_type_name>> #where_clause {
+                let mut errors = ::std::vec::Vec::new();
+                #checks
+                if errors.is_empty() {
+                    Ok(())
+                } else {
+                    Err(errors)
+                }
+            }
+        )
+    } else {
+        quote!(
+            fn validate #impl_generics (#val_param) -> ::core::result::Result<(), #error_type_name> #where_clause {
+                #checks
+                Ok(())
+            }
+        )
+    }
+}
+
+/// Generates public `sanitize()`, `validate()` and `is_valid()` associated functions, that allow
+/// to check a value without constructing the type.
+/// `validate()` sanitizes the value first, so it agrees with `new()`.
+/// `sanitize_fn` and `validate()` from `maybe_validation` are nested, so they do not overlap with
+/// outer scope imported with `use super::*`.
+///
+/// `maybe_validation` is the error type and the body of private `validate_sanitized()`, that calls
+/// the nested function and is shared with `new()`. A type without validation never fails,
+/// so it uses `Infallible` error.
+pub fn gen_sanitize_and_validate_fns(
+    inner_type: TokenStream,
+    raw_value_param: TokenStream,
+    raw_value_into_inner: TokenStream,
+    sanitized_param_type: TokenStream,
+    sanitize_fn: TokenStream,
+    maybe_validation: Option<(&syn::Path, TokenStream)>,
+) -> TokenStream {
+    let (error_type_name, validate_body, validate_sanitized) = match maybe_validation {
+        Some((error_type_name, validate_sanitized_body)) => (
+            quote!(#error_type_name),
+            quote!(Self::validate_sanitized(&Self::sanitize(#raw_value_into_inner))),
+            quote!(
+                // An arbitrary inner type may be e.g. `Cow<str>`.
+                #[allow(clippy::ptr_arg)]
+                fn validate_sanitized(value: #sanitized_param_type) -> ::core::result::Result<(), #error_type_name> {
+                    #validate_sanitized_body
+                }
+            ),
+        ),
+        None => (
+            quote!(::core::convert::Infallible),
+            quote!(
+                let _ = raw_value;
+                Ok(())
+            ),
+            quote!(),
+        ),
+    };
+
+    quote!(
+        /// Applies the sanitizers to the value.
+        pub fn sanitize(value: #inner_type) -> #inner_type {
+            #sanitize_fn
+            sanitize(value)
+        }
+
+        /// Sanitizes the value and checks it against the validators, the same way `new()` does.
+        pub fn validate(#raw_value_param) -> ::core::result::Result<(), #error_type_name> {
+            #validate_body
+        }
+
+        /// Same as `validate()`, but only tells whether the value is valid.
+        pub fn is_valid(#raw_value_param) -> bool {
+            Self::validate(raw_value).is_ok()
+        }
+
+        #validate_sanitized
+    )
+}
+
+/// Generates `new_unchecked()` (see `new_unchecked` option), that skips sanitization and
+/// validation. If the type has validation, it still runs in debug builds.
+pub fn gen_new_unchecked(
+    type_name: &TypeName,
+    raw_value_param: TokenStream,
+    raw_value_into_inner: TokenStream,
+    has_validation: bool,
+) -> TokenStream {
+    let debug_validation = has_validation.then(|| {
+        let message = format!("`{type_name}::new_unchecked()` received an invalid value.");
+        quote!(
+            ::core::debug_assert!(Self::validate_sanitized(&value).is_ok(), #message);
+        )
+    });
+    quote!(
+        /// Creates a value without sanitization and validation.
+        /// In debug builds the validation still runs and panics on an invalid value.
+        ///
+        /// # Safety
+        /// The caller must guarantee that the value is already sanitized and valid,
+        /// otherwise the guarantees of the type do not hold.
+        pub unsafe fn new_unchecked(#raw_value_param) -> Self {
+            let value = #raw_value_into_inner;
+            #debug_validation
+            Self(value)
+        }
+    )
+}
+
+/// Generates `new_const()`, that allows to define constants, e.g. `const PORT: Port = Port::new_const(8080);`.
+/// The checks (see `gen_const_check()`) panic on an invalid value, and a panic during const
+/// evaluation fails the compilation.
+pub fn gen_new_const(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    checks: TokenStream,
+) -> TokenStream {
+    quote!(
+        impl #type_name {
+            /// Creates a value in a const context, e.g. `const VALUE: Type = Type::new_const(42);`.
+            /// An invalid value fails the compilation.
+            /// All the validators are checked, a type with validators, that can't be
+            /// evaluated in a const context, gets no `new_const()`.
+            ///
+            /// # Panics
+            /// Panics if the value is invalid and the function is called at runtime.
+            pub const fn new_const(val: #inner_type) -> Self {
+                #checks
+                Self(val)
+            }
+        }
+    )
+}
+
+/// Generates a check for `new_const()`, that panics if `violation` condition is true.
+pub fn gen_const_check(
+    type_name: &TypeName,
+    violation: TokenStream,
+    validator_kind: impl std::fmt::Display,
+) -> TokenStream {
+    let message = format!(
+        "`{type_name}::new_const()` received a value, that violates `{validator_kind}` validator."
+    );
+    quote!(
+        if #violation {
+            panic!(#message);
+        }
+    )
+}
+
+/// Implements `nutype::Nutype` trait, that every nutype implements.
+/// `maybe_error_type_name` is `None` for types without validation.
+pub fn gen_impl_nutype_trait(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    generics: &Generics,
+    maybe_error_type_name: Option<&syn::Path>,
+) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let (error_type_name, new) = match maybe_error_type_name {
+        Some(error_type_name) => (quote!(#error_type_name), quote!(#type_name::new(inner))),
+        None => (
+            quote!(::core::convert::Infallible),
+            quote!(Ok(#type_name::new(inner))),
+        ),
+    };
+
+    quote!(
+        impl #impl_generics ::nutype::Nutype for #type_name #type_generics #where_clause {
+            type Inner = #inner_type;
+            type Error = #error_type_name;
+
+            fn new(inner: Self::Inner) -> ::core::result::Result<Self, Self::Error> {
+                #new
+            }
+
+            fn into_inner(self) -> Self::Inner {
+                self.0
+            }
+
+            fn as_inner(&self) -> &Self::Inner {
+                &self.0
+            }
+        }
+    )
+}
+
+/// Generates `CONSTRAINTS` associated constant (see `nutype::constraints`), that describes
+/// the type as plain data. `sanitizers` and `validators` are built with `gen_constraint_rule()`.
+pub fn gen_impl_constraints(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    generics: &Generics,
+    sanitizers: Vec<TokenStream>,
+    validators: Vec<TokenStream>,
+) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    quote!(
+        impl #impl_generics #type_name #type_generics #where_clause {
+            /// Describes the inner type, the sanitizers and the validators of the type.
+            // Arguments are cast to the widest type, e.g. `18u8 as u128`.
+            #[allow(clippy::unnecessary_cast)]
+            pub const CONSTRAINTS: ::nutype::constraints::Constraints = ::nutype::constraints::Constraints {
+                inner_type: ::core::stringify!(#inner_type),
+                sanitizers: &[#(#sanitizers),*],
+                validators: &[#(#validators),*],
+            };
+        }
+    )
+}
+
+/// Generates `nutype::constraints::Rule`. `args` are built with `ConstraintValue::gen()`.
+pub fn gen_constraint_rule(name: impl std::fmt::Display, args: &[TokenStream]) -> TokenStream {
+    let name = name.to_string();
+    quote!(
+        ::nutype::constraints::Rule {
+            name: #name,
+            args: &[#(#args),*],
+        }
+    )
+}
+
+/// Variants of `nutype::constraints::Value`.
+#[derive(Debug, Clone, Copy)]
+pub enum ConstraintValue {
+    Int,
+    UInt,
+    Float,
+    Str,
+}
+
+impl ConstraintValue {
+    /// Generates the value, e.g. `Value::UInt(20usize as u128)`.
+    pub fn gen(self, value: impl ToTokens) -> TokenStream {
+        let value_path = quote!(::nutype::constraints::Value);
+        match self {
+            Self::Int => quote!(#value_path::Int(#value as i128)),
+            Self::UInt => quote!(#value_path::UInt(#value as u128)),
+            Self::Float => quote!(#value_path::Float(#value as f64)),
+            Self::Str => quote!(#value_path::Str(#value)),
+        }
+    }
+}
+
 pub fn gen_module_name_for_type(type_name: &TypeName) -> Ident {
     let module_name = format!("__nutype_private_{type_name}__");
     Ident::new(&module_name, Span::call_site())
@@ -1,13 +1,22 @@
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 
-use crate::common::models::TypeName;
+use crate::common::models::{ErrorOptions, TypeName};
 
 pub fn gen_error_type_name(type_name: &TypeName) -> Ident {
     let error_name_str = format!("{type_name}Error");
     Ident::new(&error_name_str, Span::call_site())
 }
 
+/// Returns the error type returned by `new()`: either the one provided by the user
+/// with `error = ...` or the generated one (`{TypeName}Error`).
+pub fn gen_error_type_path(type_name: &TypeName, error_options: &ErrorOptions) -> syn::Path {
+    match &error_options.custom_type {
+        Some(custom_type) => custom_type.clone(),
+        None => gen_error_type_name(type_name).into(),
+    }
+}
+
 pub fn gen_impl_error_trait(error_type_name: &Ident) -> TokenStream {
     quote! {
         impl ::std::error::Error for #error_type_name {
@@ -17,3 +26,54 @@ pub fn gen_impl_error_trait(error_type_name: &Ident) -> TokenStream {
         }
     }
 }
+
+/// Generates a variant of an error enum for a violated bound, e.g.:
+/// * `TooLong,`
+/// * `TooLong { max: usize, actual: usize },` (with `detailed_errors`)
+pub fn gen_bound_error_variant(
+    variant: &str,
+    bound_name: &str,
+    bound_type: impl ToTokens,
+    detailed_errors: bool,
+) -> TokenStream {
+    let variant = Ident::new(variant, Span::call_site());
+    if detailed_errors {
+        let bound_name = Ident::new(bound_name, Span::call_site());
+        quote!(#variant { #bound_name: #bound_type, actual: #bound_type },)
+    } else {
+        quote!(#variant,)
+    }
+}
+
+/// Generates an expression that constructs an error for a violated bound, e.g.:
+/// * `UsernameError::TooLong`
+/// * `UsernameError::TooLong { max: 32, actual: val.len() }` (with `detailed_errors`)
+pub fn gen_bound_error(
+    error_type_name: &syn::Path,
+    variant: &str,
+    bound_name: &str,
+    bound: impl ToTokens,
+    actual: TokenStream,
+    detailed_errors: bool,
+) -> TokenStream {
+    let variant = Ident::new(variant, Span::call_site());
+    if detailed_errors {
+        let bound_name = Ident::new(bound_name, Span::call_site());
+        quote!(#error_type_name::#variant { #bound_name: #bound, actual: #actual })
+    } else {
+        quote!(#error_type_name::#variant)
+    }
+}
+
+/// Default error message for a named custom validator, derived from its name, e.g.:
+/// `NotReserved` -> `not reserved`
+pub fn gen_named_validator_message(name: &Ident) -> String {
+    let mut message = String::new();
+    for ch in name.to_string().chars() {
+        if ch.is_uppercase() && !message.is_empty() {
+            message.push(' ');
+        }
+        message.extend(ch.to_lowercase());
+    }
+    message
+}
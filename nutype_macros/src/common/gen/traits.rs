@@ -1,7 +1,8 @@
 use std::collections::HashSet;
 
 use proc_macro2::{Ident, TokenStream};
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, Attribute, Generics, Lit, Meta, MetaNameValue};
 
 use crate::common::models::TypeName;
 
@@ -51,23 +52,34 @@ where
     }
 }
 
-pub fn gen_impl_trait_into(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+pub fn gen_impl_trait_into(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
     // NOTE: We're getting blank implementation of
     //     Into<Inner> for Type
     // by implementing
     //     From<Type> for Inner
     quote! {
-        impl ::core::convert::From<#type_name> for #inner_type {
-            fn from(value: #type_name) -> Self {
+        impl #impl_generics ::core::convert::From<#type_name #type_generics> for #inner_type #where_clause {
+            fn from(value: #type_name #type_generics) -> Self {
                 value.into_inner()
             }
         }
     }
 }
 
-pub fn gen_impl_trait_as_ref(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+pub fn gen_impl_trait_as_ref(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl ::core::convert::AsRef<#inner_type> for #type_name {
+        impl #impl_generics ::core::convert::AsRef<#inner_type> for #type_name #type_generics #where_clause {
             fn as_ref(&self) -> &#inner_type {
                 &self.0
             }
@@ -75,9 +87,27 @@ pub fn gen_impl_trait_as_ref(type_name: &TypeName, inner_type: impl ToTokens) ->
     }
 }
 
-pub fn gen_impl_trait_dislpay(type_name: &TypeName) -> TokenStream {
+pub fn gen_impl_trait_deref(
+    type_name: &TypeName,
+    generics: &Generics,
+    target: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::core::ops::Deref for #type_name #type_generics #where_clause {
+            type Target = #target;
+
+            fn deref(&self) -> &#target {
+                &self.0
+            }
+        }
+    }
+}
+
+pub fn gen_impl_trait_dislpay(type_name: &TypeName, generics: &Generics) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl ::core::fmt::Display for #type_name {
+        impl #impl_generics ::core::fmt::Display for #type_name #type_generics #where_clause {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 use ::core::fmt::Display;
                 self.0.fmt(f)
@@ -87,11 +117,13 @@ pub fn gen_impl_trait_dislpay(type_name: &TypeName) -> TokenStream {
 }
 
 pub fn gen_impl_trait_borrow(
-    type_name: impl ToTokens,
+    type_name: &TypeName,
+    generics: &Generics,
     borrowed_type: impl ToTokens,
 ) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl ::core::borrow::Borrow<#borrowed_type> for #type_name {
+        impl #impl_generics ::core::borrow::Borrow<#borrowed_type> for #type_name #type_generics #where_clause {
             fn borrow(&self) -> &#borrowed_type {
                 &self.0
             }
@@ -99,9 +131,14 @@ pub fn gen_impl_trait_borrow(
     }
 }
 
-pub fn gen_impl_trait_from(type_name: impl ToTokens, inner_type: impl ToTokens) -> TokenStream {
+pub fn gen_impl_trait_from(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl ::core::convert::From<#inner_type> for #type_name {
+        impl #impl_generics ::core::convert::From<#inner_type> for #type_name #type_generics #where_clause {
             fn from(raw_value: #inner_type) -> Self {
                 Self::new(raw_value)
             }
@@ -110,15 +147,17 @@ pub fn gen_impl_trait_from(type_name: impl ToTokens, inner_type: impl ToTokens)
 }
 
 pub fn gen_impl_trait_try_from(
-    type_name: impl ToTokens,
+    type_name: &TypeName,
+    generics: &Generics,
     inner_type: impl ToTokens,
     error_type_name: impl ToTokens,
 ) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl ::core::convert::TryFrom<#inner_type> for #type_name {
+        impl #impl_generics ::core::convert::TryFrom<#inner_type> for #type_name #type_generics #where_clause {
             type Error = #error_type_name;
 
-            fn try_from(raw_value: #inner_type) -> Result<#type_name, Self::Error> {
+            fn try_from(raw_value: #inner_type) -> ::core::result::Result<Self, Self::Error> {
                 Self::new(raw_value)
             }
         }
@@ -129,7 +168,7 @@ pub fn gen_impl_trait_try_from(
 pub fn gen_impl_trait_from_str(
     type_name: &TypeName,
     inner_type: impl ToTokens,
-    maybe_error_type_name: Option<&Ident>,
+    maybe_error_type_name: Option<&syn::Path>,
 ) -> TokenStream {
     let parse_error_type_name = gen_parse_error_name(type_name);
     let def_parse_error = gen_def_parse_error(
@@ -170,10 +209,11 @@ pub fn gen_impl_trait_from_str(
     }
 }
 
-pub fn gen_impl_trait_serde_serialize(type_name: &TypeName) -> TokenStream {
+pub fn gen_impl_trait_serde_serialize(type_name: &TypeName, generics: &Generics) -> TokenStream {
     let type_name_str = type_name.to_string();
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
     quote! {
-        impl ::serde::Serialize for #type_name {
+        impl #impl_generics ::serde::Serialize for #type_name #type_generics #where_clause {
             fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
             where
                 S: ::serde::Serializer
@@ -186,25 +226,485 @@ pub fn gen_impl_trait_serde_serialize(type_name: &TypeName) -> TokenStream {
 
 pub fn gen_impl_trait_serde_deserialize(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: impl ToTokens,
-    maybe_error_type_name: Option<&Ident>,
+    maybe_error_type_name: Option<&syn::Path>,
 ) -> TokenStream {
+    let (_, type_generics, _) = generics.split_for_impl();
+    let mut de_generics = generics.clone();
+    de_generics.params.insert(0, parse_quote!('de));
+    let (impl_generics, _, where_clause) = de_generics.split_for_impl();
+
     let raw_value_to_result: TokenStream = if maybe_error_type_name.is_some() {
         quote! {
-            #type_name::new(raw_value).map_err(<D::Error as serde::de::Error>::custom)
+            Self::new(raw_value).map_err(<D::Error as ::serde::de::Error>::custom)
         }
     } else {
         quote! {
-            Ok(#type_name::new(raw_value))
+            Ok(Self::new(raw_value))
         }
     };
 
     quote! {
-        impl<'de> ::serde::Deserialize<'de> for #type_name {
-            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-                let raw_value = #inner_type::deserialize(deserializer)?;
+        impl #impl_generics ::serde::Deserialize<'de> for #type_name #type_generics #where_clause {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                let raw_value = <#inner_type as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
                 #raw_value_to_result
             }
         }
     }
 }
+
+/// Generate implementation of `JsonSchema` trait (schemars 0.8).
+/// The schema of the inner type is refined with `schema_refinements`: statements generated by
+/// `gen_json_schema_refinements()`, that modify `schema_object`.
+pub fn gen_impl_trait_schemars_json_schema(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    schema_refinements: &TokenStream,
+) -> TokenStream {
+    let type_name_str = type_name.to_string();
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    // Definitions are shared by name, which is ambiguous for different instantiations
+    // of a generic type, so generic types are always inlined.
+    let maybe_is_referenceable = if generics.params.is_empty() {
+        quote!()
+    } else {
+        quote!(
+            fn is_referenceable() -> bool {
+                false
+            }
+        )
+    };
+
+    quote! {
+        impl #impl_generics ::schemars::JsonSchema for #type_name #type_generics #where_clause {
+            fn schema_name() -> ::std::string::String {
+                #type_name_str.to_owned()
+            }
+
+            fn schema_id() -> ::std::borrow::Cow<'static, str> {
+                ::std::borrow::Cow::Borrowed(::core::concat!(::core::module_path!(), "::", #type_name_str))
+            }
+
+            #maybe_is_referenceable
+
+            // Bounds are cast to `f64`, which is a no-op for `f64` based types.
+            #[allow(clippy::unnecessary_cast)]
+            fn json_schema(gen: &mut ::schemars::gen::SchemaGenerator) -> ::schemars::schema::Schema {
+                let mut schema_object = <#inner_type as ::schemars::JsonSchema>::json_schema(gen).into_object();
+                #schema_refinements
+                ::schemars::schema::Schema::Object(schema_object)
+            }
+        }
+    }
+}
+
+/// Generate implementation of `Type`, `Encode` and `Decode` traits (sqlx 0.8) for every database,
+/// that supports the inner type. Encoding is delegated to the inner type, while decoded values go
+/// through `new()`, so rows that violate the validation rules are rejected.
+pub fn gen_impl_trait_sqlx_type(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&syn::Path>,
+) -> TokenStream {
+    let type_name_str = type_name.to_string();
+    let (_, type_generics, _) = generics.split_for_impl();
+    let self_type = quote!(#type_name #type_generics);
+
+    let type_generics = add_sqlx_params(generics, None, quote!(#inner_type: ::sqlx::Type<DB>));
+    let (type_impl_generics, _, type_where_clause) = type_generics.split_for_impl();
+
+    let encode_generics = add_sqlx_params(
+        generics,
+        Some(parse_quote!('q)),
+        quote!(#inner_type: ::sqlx::Encode<'q, DB>),
+    );
+    let (encode_impl_generics, _, encode_where_clause) = encode_generics.split_for_impl();
+
+    let decode_generics = add_sqlx_params(
+        generics,
+        Some(parse_quote!('r)),
+        quote!(#inner_type: ::sqlx::Decode<'r, DB>),
+    );
+    let (decode_impl_generics, _, decode_where_clause) = decode_generics.split_for_impl();
+
+    let raw_value_to_result = if maybe_error_type_name.is_some() {
+        quote! {
+            Self::new(raw_value).map_err(|err| {
+                ::std::format!("Failed to decode {}: {}", #type_name_str, err).into()
+            })
+        }
+    } else {
+        quote! {
+            Ok(Self::new(raw_value))
+        }
+    };
+
+    quote! {
+        impl #type_impl_generics ::sqlx::Type<DB> for #self_type #type_where_clause {
+            fn type_info() -> <DB as ::sqlx::Database>::TypeInfo {
+                <#inner_type as ::sqlx::Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &<DB as ::sqlx::Database>::TypeInfo) -> bool {
+                <#inner_type as ::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl #encode_impl_generics ::sqlx::Encode<'q, DB> for #self_type #encode_where_clause {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as ::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> ::core::result::Result<::sqlx::encode::IsNull, ::sqlx::error::BoxDynError> {
+                <#inner_type as ::sqlx::Encode<'q, DB>>::encode_by_ref(&self.0, buf)
+            }
+
+            fn produces(&self) -> ::core::option::Option<<DB as ::sqlx::Database>::TypeInfo> {
+                <#inner_type as ::sqlx::Encode<'q, DB>>::produces(&self.0)
+            }
+
+            fn size_hint(&self) -> usize {
+                <#inner_type as ::sqlx::Encode<'q, DB>>::size_hint(&self.0)
+            }
+        }
+
+        impl #decode_impl_generics ::sqlx::Decode<'r, DB> for #self_type #decode_where_clause {
+            fn decode(
+                value: <DB as ::sqlx::Database>::ValueRef<'r>,
+            ) -> ::core::result::Result<Self, ::sqlx::error::BoxDynError> {
+                let raw_value = <#inner_type as ::sqlx::Decode<'r, DB>>::decode(value)?;
+                #raw_value_to_result
+            }
+        }
+    }
+}
+
+/// Extends the generics of a nutype with `DB` parameter (and optionally a lifetime),
+/// which are needed to implement sqlx traits, e.g. `impl<'q, DB> Encode<'q, DB> for Username`.
+fn add_sqlx_params(
+    generics: &Generics,
+    maybe_lifetime: Option<syn::Lifetime>,
+    predicate: TokenStream,
+) -> Generics {
+    let mut generics = generics.clone();
+    if let Some(lifetime) = maybe_lifetime {
+        generics.params.insert(0, parse_quote!(#lifetime));
+    }
+    generics.params.push(parse_quote!(DB: ::sqlx::Database));
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#predicate));
+    generics
+}
+
+/// Generate statements, that set the description (taken from the doc comment of the type)
+/// and the `constraints` on `schema_object` within `JsonSchema::json_schema()`.
+pub fn gen_json_schema_refinements(
+    doc_attrs: &[Attribute],
+    constraints: TokenStream,
+) -> TokenStream {
+    let maybe_description = doc_attrs_to_description(doc_attrs).map(|description| {
+        quote!(
+            schema_object.metadata().description = ::core::option::Option::Some(#description.to_owned());
+        )
+    });
+    quote!(
+        #maybe_description
+        #constraints
+    )
+}
+
+/// Join lines of `///` doc comments, the same way `#[derive(JsonSchema)]` of schemars does.
+fn doc_attrs_to_description(doc_attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = doc_attrs
+        .iter()
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(lit_str),
+                ..
+            })) if attr.path.is_ident("doc") => Some(lit_str.value()),
+            _ => None,
+        })
+        .collect();
+    let description = lines
+        .iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let description = description.trim();
+    if description.is_empty() {
+        None
+    } else {
+        Some(description.to_owned())
+    }
+}
+
+/// Generate implementation of `Default` trait with the value set by `default = ...`.
+/// The default value goes through `new()`, so it's sanitized and validated like any other value.
+/// Literals are checked against the built-in validators at compile time (see `validate_*_default_value()`),
+/// any other invalid default value makes `default()` panic.
+pub fn gen_impl_trait_default(
+    type_name: &TypeName,
+    generics: &Generics,
+    default_value: &syn::Expr,
+    has_validation: bool,
+) -> TokenStream {
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    if has_validation {
+        let panic_msg = format!("Default value for type `{type_name}` is invalid");
+        quote! {
+            impl #impl_generics ::core::default::Default for #type_name #type_generics #where_clause {
+                fn default() -> Self {
+                    match Self::new(#default_value) {
+                        Ok(value) => value,
+                        Err(_) => panic!(#panic_msg),
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics ::core::default::Default for #type_name #type_generics #where_clause {
+                fn default() -> Self {
+                    Self::new(#default_value)
+                }
+            }
+        }
+    }
+}
+
+/// Binary arithmetic operators, that can be derived for number based types.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ArithmeticOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl ArithmeticOperator {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Sub => "sub",
+            Self::Mul => "mul",
+            Self::Div => "div",
+        }
+    }
+
+    fn trait_path(&self) -> TokenStream {
+        match self {
+            Self::Add => quote!(::core::ops::Add),
+            Self::Sub => quote!(::core::ops::Sub),
+            Self::Mul => quote!(::core::ops::Mul),
+            Self::Div => quote!(::core::ops::Div),
+        }
+    }
+
+    fn operator(&self) -> TokenStream {
+        match self {
+            Self::Add => quote!(+),
+            Self::Sub => quote!(-),
+            Self::Mul => quote!(*),
+            Self::Div => quote!(/),
+        }
+    }
+
+    /// Generates the operation on the given operands, e.g. `lhs + rhs`.
+    pub fn apply(&self, lhs: TokenStream, rhs: TokenStream) -> TokenStream {
+        let op = self.operator();
+        quote!(#lhs #op #rhs)
+    }
+
+    pub fn checked_method_name(&self) -> Ident {
+        format_ident!("checked_{}", self.name())
+    }
+
+    pub fn saturating_method_name(&self) -> Ident {
+        format_ident!("saturating_{}", self.name())
+    }
+}
+
+/// Bounds to clamp results of `saturating_*` methods to.
+/// They're available only if a clamped value is guaranteed to be valid,
+/// e.g. when `min` and `max` are the only validators.
+pub struct SaturationBounds {
+    pub min: Option<TokenStream>,
+    pub max: Option<TokenStream>,
+
+    /// `false` if division may produce a value, that can't be clamped,
+    /// e.g. NaN of `0.0 / 0.0` for a `finite` float. Then `saturating_div` is not generated.
+    pub clamps_div: bool,
+}
+
+/// Output of arithmetic operations: the result has to be validated again, unless
+/// there is no validation.
+fn gen_arithmetic_output(maybe_error_type_name: Option<&syn::Path>) -> TokenStream {
+    match maybe_error_type_name {
+        Some(error_type_name) => quote!(::core::result::Result<Self, #error_type_name>),
+        None => quote!(Self),
+    }
+}
+
+/// Generates the arithmetic operator trait together with `checked_*` and `saturating_*` methods.
+/// `value` evaluates the operation on `self.0` and `rhs.0` for the operator trait, it must not
+/// wrap on overflow, `checked_value` may use `?` to bail out,
+/// `saturated_value` evaluates the operation without overflowing.
+pub fn gen_arithmetic(
+    type_name: &TypeName,
+    operator: ArithmeticOperator,
+    maybe_error_type_name: Option<&syn::Path>,
+    maybe_saturation_bounds: Option<&SaturationBounds>,
+    value: TokenStream,
+    checked_value: TokenStream,
+    saturated_value: TokenStream,
+) -> TokenStream {
+    let has_validation = maybe_error_type_name.is_some();
+    let impl_trait = gen_impl_trait_arithmetic(type_name, operator, maybe_error_type_name, value);
+    let checked_method =
+        gen_checked_arithmetic_method(type_name, operator, checked_value, has_validation);
+    let saturating_method = maybe_saturation_bounds
+        .filter(|bounds| operator != ArithmeticOperator::Div || bounds.clamps_div)
+        .map(|bounds| {
+            gen_saturating_arithmetic_method(
+                type_name,
+                operator,
+                saturated_value,
+                bounds,
+                has_validation,
+            )
+        });
+
+    quote! {
+        #impl_trait
+        #checked_method
+        #saturating_method
+    }
+}
+
+fn gen_impl_trait_arithmetic(
+    type_name: &TypeName,
+    operator: ArithmeticOperator,
+    maybe_error_type_name: Option<&syn::Path>,
+    value: TokenStream,
+) -> TokenStream {
+    let trait_path = operator.trait_path();
+    let method_name = format_ident!("{}", operator.name());
+    let output = gen_arithmetic_output(maybe_error_type_name);
+
+    quote! {
+        impl #trait_path for #type_name {
+            type Output = #output;
+
+            fn #method_name(self, rhs: Self) -> Self::Output {
+                Self::new(#value)
+            }
+        }
+    }
+}
+
+/// `value` negates `self.0`, it must not wrap on overflow.
+pub fn gen_impl_trait_neg(
+    type_name: &TypeName,
+    maybe_error_type_name: Option<&syn::Path>,
+    value: TokenStream,
+) -> TokenStream {
+    let output = gen_arithmetic_output(maybe_error_type_name);
+
+    quote! {
+        impl ::core::ops::Neg for #type_name {
+            type Output = #output;
+
+            fn neg(self) -> Self::Output {
+                Self::new(#value)
+            }
+        }
+    }
+}
+
+/// `sum_value` sums the inner values of `iter`, it must not wrap on overflow.
+pub fn gen_impl_trait_sum(
+    type_name: &TypeName,
+    maybe_error_type_name: Option<&syn::Path>,
+    sum_value: TokenStream,
+) -> TokenStream {
+    let output = match maybe_error_type_name {
+        Some(error_type_name) => quote!(::core::result::Result<#type_name, #error_type_name>),
+        None => quote!(#type_name),
+    };
+
+    quote! {
+        impl ::core::iter::Sum<#type_name> for #output {
+            fn sum<I: ::core::iter::Iterator<Item = #type_name>>(iter: I) -> Self {
+                #type_name::new(#sum_value)
+            }
+        }
+    }
+}
+
+/// Generates `checked_*` method, that returns `None` if the operation fails or its result is invalid.
+fn gen_checked_arithmetic_method(
+    type_name: &TypeName,
+    operator: ArithmeticOperator,
+    checked_value: TokenStream,
+    has_validation: bool,
+) -> TokenStream {
+    let method_name = operator.checked_method_name();
+    let result = if has_validation {
+        quote!(Self::new(value).ok())
+    } else {
+        quote!(Some(Self::new(value)))
+    };
+
+    quote! {
+        impl #type_name {
+            pub fn #method_name(self, rhs: Self) -> ::core::option::Option<Self> {
+                let value = #checked_value;
+                #result
+            }
+        }
+    }
+}
+
+/// Generates `saturating_*` method, that clamps the result of the operation to the bounds of the type.
+fn gen_saturating_arithmetic_method(
+    type_name: &TypeName,
+    operator: ArithmeticOperator,
+    saturated_value: TokenStream,
+    bounds: &SaturationBounds,
+    has_validation: bool,
+) -> TokenStream {
+    let method_name = operator.saturating_method_name();
+    let clamp_min = bounds.min.as_ref().map(|min| {
+        quote!(
+            let value = if value < #min { #min } else { value };
+        )
+    });
+    let clamp_max = bounds.max.as_ref().map(|max| {
+        quote!(
+            let value = if value > #max { #max } else { value };
+        )
+    });
+    // The clamped value satisfies all the validators, so it's safe to construct the type directly.
+    let result = if has_validation {
+        quote!(Self(value))
+    } else {
+        quote!(Self::new(value))
+    };
+
+    quote! {
+        impl #type_name {
+            pub fn #method_name(self, rhs: Self) -> Self {
+                let value = #saturated_value;
+                #clamp_min
+                #clamp_max
+                #result
+            }
+        }
+    }
+}
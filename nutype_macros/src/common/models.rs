@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
 
 use proc_macro2::{Ident, Span, TokenStream};
@@ -8,7 +9,7 @@ use syn::Attribute;
 /// A trait that may be implemented by enums with payload.
 /// It's mostly used to detect duplicates of validators and sanitizers regardless of their payload.
 pub trait Kind {
-    type Kind: PartialEq + Eq + Debug + Clone + Copy;
+    type Kind: PartialEq + Eq + Debug + Clone;
 
     fn kind(&self) -> Self::Kind;
 }
@@ -35,12 +36,28 @@ impl<T: Kind> Kind for SpannedItem<T> {
     }
 }
 
+/// A custom validator that reports its own error variant instead of the generic `Invalid`.
+/// It's set with:
+///     with(name = NotReserved, fn = is_not_reserved)
+#[derive(Debug)]
+pub struct NamedCustomValidator {
+    /// Name of the error variant, e.g. `NotReserved`.
+    pub name: Ident,
+
+    /// Predicate: a closure or a path to a function.
+    pub func: TokenStream,
+}
+
 /// Represents the inner type of a newtype.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InnerType {
     String,
     Integer(IntegerType),
     Float(FloatType),
+
+    /// Any other type (e.g. `Uuid` or `Vec<u8>`), that supports only custom sanitizers and
+    /// validators.
+    Any(Box<syn::Type>),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -65,6 +82,24 @@ pub enum FloatType {
     F64,
 }
 
+impl FloatType {
+    /// The max number of decimal places for `round(n)`, so that `10^n` is finite.
+    pub fn max_round_decimals(&self) -> u32 {
+        match self {
+            Self::F32 => f32::MAX_10_EXP as u32,
+            Self::F64 => f64::MAX_10_EXP as u32,
+        }
+    }
+
+    /// Machine epsilon of the type, converted to `f64`.
+    pub fn epsilon(&self) -> f64 {
+        match self {
+            Self::F32 => f32::EPSILON as f64,
+            Self::F64 => f64::EPSILON,
+        }
+    }
+}
+
 impl ToTokens for InnerType {
     fn to_tokens(&self, token_stream: &mut TokenStream) {
         match self {
@@ -77,10 +112,22 @@ impl ToTokens for InnerType {
             InnerType::Float(float_type) => {
                 float_type.to_tokens(token_stream);
             }
+            InnerType::Any(tp) => {
+                tp.to_tokens(token_stream);
+            }
         };
     }
 }
 
+impl IntegerType {
+    pub fn is_signed(&self) -> bool {
+        match self {
+            Self::U8 | Self::U16 | Self::U32 | Self::U64 | Self::U128 | Self::Usize => false,
+            Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128 | Self::Isize => true,
+        }
+    }
+}
+
 impl ToTokens for IntegerType {
     fn to_tokens(&self, token_stream: &mut TokenStream) {
         let type_stream = match self {
@@ -135,6 +182,7 @@ impl ToTokens for TypeName {
 #[derive(Debug)]
 pub struct NewtypeMeta {
     pub type_name: TypeName,
+    pub generics: syn::Generics,
     pub inner_type: InnerType,
     pub vis: syn::Visibility,
     pub doc_attrs: Vec<Attribute>,
@@ -160,6 +208,83 @@ impl<Sanitizer, Validator> Guard<Sanitizer, Validator> {
             Self::WithValidation { .. } => true,
         }
     }
+
+    pub fn sanitizers(&self) -> &[Sanitizer] {
+        match self {
+            Self::WithoutValidation { sanitizers } => sanitizers,
+            Self::WithValidation { sanitizers, .. } => sanitizers,
+        }
+    }
+
+    /// Empty, if there is no validation.
+    pub fn validators(&self) -> &[Validator] {
+        match self {
+            Self::WithoutValidation { .. } => &[],
+            Self::WithValidation { validators, .. } => validators,
+        }
+    }
+}
+
+/// Top level attributes of `#[nutype(...)]`.
+/// The guard is either raw (parsed, but not yet validated) or validated.
+#[derive(Debug)]
+pub struct Attributes<G> {
+    pub guard: G,
+    pub error_options: ErrorOptions,
+
+    /// Set by `default = ...`: the value used to implement `Default` trait.
+    pub default: Option<syn::Expr>,
+
+    /// Set by `new_unchecked`: additionally generate `unsafe fn new_unchecked()`, that skips
+    /// sanitization and validation.
+    pub new_unchecked: bool,
+}
+
+impl<G> Attributes<G> {
+    pub fn try_map_guard<G2>(
+        self,
+        f: impl FnOnce(G) -> Result<G2, syn::Error>,
+    ) -> Result<Attributes<G2>, syn::Error> {
+        let Attributes {
+            guard,
+            error_options,
+            default,
+            new_unchecked,
+        } = self;
+        Ok(Attributes {
+            guard: f(guard)?,
+            error_options,
+            default,
+            new_unchecked,
+        })
+    }
+}
+
+/// Options that affect the generated error type and the way validation errors are reported.
+#[derive(Debug, Default)]
+pub struct ErrorOptions {
+    /// Set by `detailed_errors`: error variants carry the violated bound and the actual value,
+    /// e.g. `TooLong { max: 32, actual: 40 }`.
+    pub detailed: bool,
+
+    /// Set by `collect_errors`: additionally generate `new_collecting()` that reports
+    /// all the violated validation rules instead of the first one.
+    pub collect: bool,
+
+    /// Set by `error = MyError`: the user-provided error type, that is returned by `with`
+    /// validators. No error type is generated in that case.
+    pub custom_type: Option<syn::Path>,
+
+    /// Custom error messages set per validator, e.g. `max_len(32, message = "...")`.
+    /// The key is the name of the validator (e.g. `max_len`), there can be only one validator
+    /// of every kind. Named custom validators are keyed by their name (e.g. `NotReserved`).
+    pub messages: HashMap<String, String>,
+}
+
+impl ErrorOptions {
+    pub fn message_for(&self, validator: &impl Kind<Kind = impl ToString>) -> Option<&String> {
+        self.messages.get(&validator.kind().to_string())
+    }
 }
 
 /// Parsed by not yet validated
@@ -187,12 +312,20 @@ pub enum NormalDeriveTrait {
     Ord,
     FromStr,
     AsRef,
+    Deref,
     From,
     TryFrom,
     Into,
     Hash,
     Borrow,
     Display,
+    Default,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg,
+    Sum,
 
     // External crates
     //
@@ -201,6 +334,12 @@ pub enum NormalDeriveTrait {
 
     #[cfg_attr(not(feature = "serde1"), allow(dead_code))]
     SerdeDeserialize,
+
+    #[cfg_attr(not(feature = "schemars"), allow(dead_code))]
+    SchemarsJsonSchema,
+
+    #[cfg_attr(not(feature = "sqlx"), allow(dead_code))]
+    SqlxType,
 }
 
 pub type SpannedDeriveTrait = SpannedItem<DeriveTrait>;
@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
-use syn::{spanned::Spanned, Attribute, DeriveInput, Visibility};
+use syn::{spanned::Spanned, Attribute, DeriveInput, Generics, Visibility};
 
 use crate::common::models::{FloatType, IntegerType, TypeName};
 use crate::{
@@ -17,7 +17,7 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<NewtypeMeta, syn::Error>
         data,
         vis,
         ident: type_name,
-        generics: _,
+        generics,
     } = input;
 
     let type_name = TypeName::new(type_name);
@@ -60,47 +60,37 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<NewtypeMeta, syn::Error>
     })?;
     validate_inner_field_visibility(&seg.vis)?;
 
-    let type_path = match seg.ty.clone() {
-        syn::Type::Path(tp) => tp,
-        _ => {
-            let error = syn::Error::new(
-                seg.span(),
-                "#[nutype] requires a simple inner type (e.g. String, i32, etc.)",
-            );
-            return Err(error);
+    let inner_type = match &seg.ty {
+        syn::Type::Path(type_path) => {
+            let type_path_str = type_path.into_token_stream().to_string();
+            match type_path_str.as_ref() {
+                "String" => InnerType::String,
+                "u8" => InnerType::Integer(IntegerType::U8),
+                "u16" => InnerType::Integer(IntegerType::U16),
+                "u32" => InnerType::Integer(IntegerType::U32),
+                "u64" => InnerType::Integer(IntegerType::U64),
+                "u128" => InnerType::Integer(IntegerType::U128),
+                "usize" => InnerType::Integer(IntegerType::Usize),
+                "i8" => InnerType::Integer(IntegerType::I8),
+                "i16" => InnerType::Integer(IntegerType::I16),
+                "i32" => InnerType::Integer(IntegerType::I32),
+                "i64" => InnerType::Integer(IntegerType::I64),
+                "i128" => InnerType::Integer(IntegerType::I128),
+                "isize" => InnerType::Integer(IntegerType::Isize),
+                "f32" => InnerType::Float(FloatType::F32),
+                "f64" => InnerType::Float(FloatType::F64),
+                _ => InnerType::Any(Box::new(seg.ty.clone())),
+            }
         }
+        _ => InnerType::Any(Box::new(seg.ty.clone())),
     };
 
-    let type_path_str = type_path.into_token_stream().to_string();
-
-    let inner_type = match type_path_str.as_ref() {
-        "String" => InnerType::String,
-        "u8" => InnerType::Integer(IntegerType::U8),
-        "u16" => InnerType::Integer(IntegerType::U16),
-        "u32" => InnerType::Integer(IntegerType::U32),
-        "u64" => InnerType::Integer(IntegerType::U64),
-        "u128" => InnerType::Integer(IntegerType::U128),
-        "usize" => InnerType::Integer(IntegerType::Usize),
-        "i8" => InnerType::Integer(IntegerType::I8),
-        "i16" => InnerType::Integer(IntegerType::I16),
-        "i32" => InnerType::Integer(IntegerType::I32),
-        "i64" => InnerType::Integer(IntegerType::I64),
-        "i128" => InnerType::Integer(IntegerType::I128),
-        "isize" => InnerType::Integer(IntegerType::Isize),
-        "f32" => InnerType::Float(FloatType::F32),
-        "f64" => InnerType::Float(FloatType::F64),
-        tp => {
-            let error = syn::Error::new(
-                seg.span(),
-                format!("#[nutype] does not support `{tp}` as inner type."),
-            );
-            return Err(error);
-        }
-    };
+    validate_generics(&generics, &inner_type)?;
 
     Ok(NewtypeMeta {
         doc_attrs,
         type_name,
+        generics,
         inner_type,
         vis,
         derive_traits,
@@ -124,6 +114,19 @@ fn validate_supported_attrs(attrs: &[syn::Attribute]) -> Result<(), syn::Error>
     Ok(())
 }
 
+fn validate_generics(generics: &Generics, inner_type: &InnerType) -> Result<(), syn::Error> {
+    match inner_type {
+        InnerType::Any(_) => Ok(()),
+        InnerType::String | InnerType::Integer(_) | InnerType::Float(_)
+            if !generics.params.is_empty() =>
+        {
+            let msg = format!("#[nutype] does not support generic parameters for `{}` as inner type.\nGenerics make sense only for inner types that depend on them, e.g. `Vec<T>`.", inner_type.to_token_stream());
+            Err(syn::Error::new(generics.span(), msg))
+        }
+        _ => Ok(()),
+    }
+}
+
 fn validate_inner_field_visibility(vis: &Visibility) -> Result<(), syn::Error> {
     match vis {
         Visibility::Inherited => Ok(()),
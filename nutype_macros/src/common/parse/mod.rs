@@ -1,11 +1,14 @@
 pub mod meta;
 
-use std::{any::type_name, fmt::Debug, str::FromStr};
+use std::{any::type_name, collections::HashMap, fmt::Debug, iter::Peekable, str::FromStr};
 
-use proc_macro2::{Group, Ident, Span, TokenStream, TokenTree};
+use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use syn::spanned::Spanned;
 
-use crate::common::models::{DeriveTrait, NormalDeriveTrait, RawGuard, SpannedDeriveTrait};
+use crate::common::models::{
+    Attributes, DeriveTrait, ErrorOptions, NamedCustomValidator, NormalDeriveTrait, RawGuard,
+    SpannedDeriveTrait,
+};
 
 /// ## Example
 /// Input (token stream):
@@ -31,6 +34,162 @@ where
     Ok((value, iter))
 }
 
+/// Reads a number literal (e.g. `18`, `-0.5` or `100_u8`) from an expression,
+/// so it can be verified at compile time.
+/// Returns `None` if the expression is not a number literal.
+pub fn parse_literal_number<T: FromStr>(expr: &syn::Expr) -> Option<T> {
+    let (is_negative, lit) = match expr {
+        syn::Expr::Lit(expr_lit) => (false, &expr_lit.lit),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => match expr.as_ref() {
+            syn::Expr::Lit(expr_lit) => (true, &expr_lit.lit),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let digits = match lit {
+        syn::Lit::Int(lit_int) => lit_int.base10_digits(),
+        syn::Lit::Float(lit_float) => lit_float.base10_digits(),
+        _ => return None,
+    };
+    let number = if is_negative {
+        format!("-{digits}")
+    } else {
+        digits.to_string()
+    };
+    number.parse::<T>().ok()
+}
+
+/// ## Example
+/// Context:
+///     clamp(0, 100)
+/// Input (token stream):
+///     (0, 100)
+/// Output (parsed values):
+///     (0, 100)
+pub fn parse_clamp_range<T, ITER>(clamp_ident: &Ident, mut iter: ITER) -> Result<(T, T), syn::Error>
+where
+    T: FromStr,
+    ITER: Iterator<Item = TokenTree>,
+{
+    let syntax_error = || {
+        let msg = "`clamp` expects the lower and the upper bounds, e.g.:\n\n    clamp(0, 100)\n\n";
+        syn::Error::new(clamp_ident.span(), msg)
+    };
+
+    let group = match (iter.next(), iter.next()) {
+        (Some(TokenTree::Group(group)), None) if group.delimiter() == Delimiter::Parenthesis => {
+            group
+        }
+        _ => return Err(syntax_error()),
+    };
+
+    let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+    let bounds: Vec<&[TokenTree]> = tokens.split(is_comma).collect();
+    match bounds.as_slice() {
+        [min, max] if !min.is_empty() && !max.is_empty() => Ok((
+            parse_number_tokens(min, group.span())?,
+            parse_number_tokens(max, group.span())?,
+        )),
+        _ => Err(syntax_error()),
+    }
+}
+
+/// ## Example
+/// Context:
+///     round(2)
+/// Input (token stream):
+///     (2)
+/// Output (parsed value):
+///     2
+pub fn parse_parenthesized_number<T, ITER>(
+    ident: &Ident,
+    mut iter: ITER,
+    example: &str,
+) -> Result<T, syn::Error>
+where
+    T: FromStr,
+    ITER: Iterator<Item = TokenTree>,
+{
+    match (iter.next(), iter.next()) {
+        (Some(TokenTree::Group(group)), None) if group.delimiter() == Delimiter::Parenthesis => {
+            let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+            parse_number_tokens(&tokens, group.span())
+        }
+        _ => {
+            let msg =
+                format!("`{ident}` expects a number in parenthesis, e.g.:\n\n    {example}\n\n");
+            Err(syn::Error::new(ident.span(), msg))
+        }
+    }
+}
+
+/// Parses the step of `multiple_of` validator and an optional tolerance.
+///
+/// ## Example
+/// Context:
+///     multiple_of = 6
+///     multiple_of(0.05, epsilon = 0.0001)
+/// Output:
+///     (6, None)
+///     (0.05, Some(0.0001))
+pub fn parse_multiple_of<T, ITER>(
+    ident: &Ident,
+    iter: ITER,
+    allow_epsilon: bool,
+) -> Result<(T, Option<T>), syn::Error>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Debug,
+    ITER: Iterator<Item = TokenTree>,
+{
+    let mut iter = iter.peekable();
+    let group = match iter.peek() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            group.clone()
+        }
+        _ => {
+            let (step, _iter) = parse_value_as_number(iter)?;
+            return Ok((step, None));
+        }
+    };
+
+    if !allow_epsilon {
+        let msg = "`epsilon` is supported only for float based types.";
+        return Err(syn::Error::new(group.span(), msg));
+    }
+    let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+    let args: Vec<&[TokenTree]> = tokens.split(is_comma).collect();
+    match args.as_slice() {
+        [step, [TokenTree::Ident(key), eq, epsilon @ ..]]
+            if !step.is_empty() && key == "epsilon" && is_eq(eq) && !epsilon.is_empty() =>
+        {
+            let step = parse_number_tokens(step, group.span())?;
+            let epsilon = parse_number_tokens(epsilon, group.span())?;
+            Ok((step, Some(epsilon)))
+        }
+        _ => {
+            let msg = "`multiple_of` expects a step and an optional `epsilon`, e.g.:\n\n    multiple_of(0.05, epsilon = 0.0001)\n\n";
+            Err(syn::Error::new(ident.span(), msg))
+        }
+    }
+}
+
+fn parse_number_tokens<T: FromStr>(
+    tokens: &[TokenTree],
+    fallback_span: Span,
+) -> Result<T, syn::Error> {
+    let num_str: String = tokens.iter().map(|t| t.to_string()).collect();
+    sanitize_number(&num_str).parse::<T>().map_err(|_err| {
+        let span = tokens.first().map(|t| t.span()).unwrap_or(fallback_span);
+        let msg = format!("Expected {}, got `{}`", type_name::<T>(), num_str);
+        syn::Error::new(span, msg)
+    })
+}
+
 fn read_number<ITER>(iter: &mut ITER) -> Result<(String, Span), syn::Error>
 where
     ITER: Iterator<Item = TokenTree>,
@@ -75,17 +234,33 @@ pub fn try_unwrap_group(token: TokenTree) -> Result<Group, syn::Error> {
     }
 }
 
+/// Options that can be set at the top level of `#[nutype(...)]`.
+const TOP_LEVEL_OPTIONS: [&str; 7] = [
+    "sanitize",
+    "validate",
+    "detailed_errors",
+    "collect_errors",
+    "error",
+    "default",
+    "new_unchecked",
+];
+
 pub fn parse_nutype_attributes<S, V>(
     parse_sanitize_attrs: impl Fn(TokenStream) -> Result<Vec<S>, syn::Error>,
     parse_validate_attrs: impl Fn(TokenStream) -> Result<Vec<V>, syn::Error>,
-) -> impl FnOnce(TokenStream) -> Result<RawGuard<S, V>, syn::Error> {
+) -> impl FnOnce(TokenStream) -> Result<Attributes<RawGuard<S, V>>, syn::Error> {
     move |input: TokenStream| {
-        let mut output = RawGuard {
-            sanitizers: vec![],
-            validators: vec![],
+        let mut output = Attributes {
+            guard: RawGuard {
+                sanitizers: vec![],
+                validators: vec![],
+            },
+            error_options: ErrorOptions::default(),
+            default: None,
+            new_unchecked: false,
         };
 
-        let mut iter = input.into_iter();
+        let mut iter = input.into_iter().peekable();
 
         loop {
             let token = match iter.next() {
@@ -95,6 +270,11 @@ pub fn parse_nutype_attributes<S, V>(
                 }
             };
 
+            // Top level options may be optionally separated with commas.
+            if is_comma(&token) {
+                continue;
+            }
+
             let ident = try_unwrap_ident(token)?;
 
             match ident.to_string().as_ref() {
@@ -105,7 +285,7 @@ pub fn parse_nutype_attributes<S, V>(
                     })?;
                     let group = try_unwrap_group(token)?;
                     let sanitize_stream = group.stream();
-                    output.sanitizers = parse_sanitize_attrs(sanitize_stream)?;
+                    output.guard.sanitizers = parse_sanitize_attrs(sanitize_stream)?;
                 }
                 "validate" => {
                     let token = iter.next().ok_or_else(|| {
@@ -113,8 +293,26 @@ pub fn parse_nutype_attributes<S, V>(
                         syn::Error::new(ident.span(), msg)
                     })?;
                     let group = try_unwrap_group(token)?;
-                    let validate_stream = group.stream();
-                    output.validators = parse_validate_attrs(validate_stream)?;
+                    let (validate_stream, messages) = extract_validator_messages(group.stream())?;
+                    output.guard.validators = parse_validate_attrs(validate_stream)?;
+                    output.error_options.messages = messages;
+                }
+                "detailed_errors" => {
+                    output.error_options.detailed = true;
+                }
+                "collect_errors" => {
+                    output.error_options.collect = true;
+                }
+                "error" => {
+                    let path = parse_error_type_path(&ident, &mut iter)?;
+                    output.error_options.custom_type = Some(path);
+                }
+                "default" => {
+                    let expr = parse_default_value(&ident, &mut iter)?;
+                    output.default = Some(expr);
+                }
+                "new_unchecked" => {
+                    output.new_unchecked = true;
                 }
                 unknown => {
                     let msg = format!("Unknown #[nutype] option: `{unknown}`");
@@ -126,6 +324,211 @@ pub fn parse_nutype_attributes<S, V>(
     }
 }
 
+// Context:
+//   error = crate::errors::MyError
+// Input:
+//   = crate::errors::MyError
+// Output:
+//   crate::errors::MyError
+//
+// Only the tokens that form the path are consumed, so the following top level options
+// do not have to be separated with a comma.
+fn parse_error_type_path(
+    error_ident: &Ident,
+    iter: &mut Peekable<impl Iterator<Item = TokenTree>>,
+) -> Result<syn::Path, syn::Error> {
+    let syntax_error = || {
+        let msg = "Invalid syntax for `error`. Expected a path to the error type, e.g.:\n\n    error = MyError\n\n";
+        syn::Error::new(error_ident.span(), msg)
+    };
+    let is_colon =
+        |token: &TokenTree| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ':');
+
+    match iter.next() {
+        Some(ref eq_t) if is_eq(eq_t) => {}
+        _ => return Err(syntax_error()),
+    }
+
+    let mut tokens: Vec<TokenTree> = Vec::new();
+    loop {
+        while let Some(colon) = iter.next_if(is_colon) {
+            tokens.push(colon);
+        }
+        match iter.next() {
+            Some(TokenTree::Ident(ident)) => tokens.push(TokenTree::Ident(ident)),
+            _ => return Err(syntax_error()),
+        }
+        if !iter.peek().map(is_colon).unwrap_or(false) {
+            break;
+        }
+    }
+
+    syn::parse2(tokens.into_iter().collect()).map_err(|_| syntax_error())
+}
+
+// Context:
+//   default = 18
+// Input:
+//   = 18
+// Output:
+//   18
+//
+// The expression ends with a comma or with the next top level option
+// (e.g. `default = 18 validate(min = 18)`).
+fn parse_default_value(
+    default_ident: &Ident,
+    iter: &mut Peekable<impl Iterator<Item = TokenTree>>,
+) -> Result<syn::Expr, syn::Error> {
+    let syntax_error = || {
+        let msg =
+            "Invalid syntax for `default`. Expected an expression, e.g.:\n\n    default = 18\n\n";
+        syn::Error::new(default_ident.span(), msg)
+    };
+    let is_top_level_option = |token: &TokenTree| matches!(token, TokenTree::Ident(ident) if TOP_LEVEL_OPTIONS.iter().any(|opt| ident == opt));
+    let is_path_or_field_separator = |token: &TokenTree| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ':' || punct.as_char() == '.');
+
+    match iter.next() {
+        Some(ref eq_t) if is_eq(eq_t) => {}
+        _ => return Err(syntax_error()),
+    }
+
+    let mut tokens: Vec<TokenTree> = Vec::new();
+    while let Some(token) = iter.peek() {
+        let is_end = is_comma(token)
+            || (is_top_level_option(token)
+                && !tokens
+                    .last()
+                    .map(is_path_or_field_separator)
+                    .unwrap_or(false));
+        if is_end {
+            break;
+        }
+        tokens.extend(iter.next());
+    }
+
+    if tokens.is_empty() {
+        return Err(syntax_error());
+    }
+    syn::parse2(tokens.into_iter().collect())
+}
+
+/// Extracts custom error messages from validators and brings the validators to the plain syntax.
+/// Named custom validators (`with(name = .., fn = ..)`) and `multiple_of` with `epsilon`
+/// keep their parenthesis.
+///
+/// ## Example
+/// Input:
+///     not_empty(message = "name is required"), max_len(20, message = "name is too long"), min_len = 3
+/// Output:
+///     not_empty, max_len = 20, min_len = 3
+/// and messages:
+///     { "not_empty" => "name is required", "max_len" => "name is too long" }
+fn extract_validator_messages(
+    stream: TokenStream,
+) -> Result<(TokenStream, HashMap<String, String>), syn::Error> {
+    let tokens: Vec<TokenTree> = stream.into_iter().collect();
+    let mut messages = HashMap::new();
+    let mut output: Vec<TokenTree> = Vec::with_capacity(tokens.len());
+
+    for validator_tokens in tokens.split(is_comma) {
+        match validator_tokens {
+            [TokenTree::Ident(ident), TokenTree::Group(group)]
+                if group.delimiter() == Delimiter::Parenthesis =>
+            {
+                let args: Vec<TokenTree> = group.stream().into_iter().collect();
+                let mut value: Option<&[TokenTree]> = None;
+                let mut message: Option<(String, Span)> = None;
+                // Arguments of a named custom validator: `with(name = NotReserved, fn = ...)`
+                let mut named_args: Vec<&[TokenTree]> = Vec::new();
+                let mut name: Option<String> = None;
+                // Tolerance of a float validator: `multiple_of(0.05, epsilon = 0.001)`
+                let mut epsilon: Option<&[TokenTree]> = None;
+                for arg in args.split(is_comma).filter(|arg| !arg.is_empty()) {
+                    match arg {
+                        [TokenTree::Ident(key), eq, ..] if key == "message" && is_eq(eq) => {
+                            let lit: syn::LitStr = syn::parse2(arg[2..].iter().cloned().collect())
+                                .map_err(|_| {
+                                    let msg = "`message` is expected to be a string literal, e.g.:\n\n    max_len(20, message = \"name is too long\")\n\n";
+                                    syn::Error::new(key.span(), msg)
+                                })?;
+                            message = Some((lit.value(), key.span()));
+                        }
+                        [TokenTree::Ident(key), eq, rest @ ..]
+                            if ident == "with" && (key == "name" || key == "fn") && is_eq(eq) =>
+                        {
+                            if key == "name" {
+                                name =
+                                    Some(TokenStream::from_iter(rest.iter().cloned()).to_string());
+                            }
+                            named_args.push(arg);
+                        }
+                        [TokenTree::Ident(key), eq, ..]
+                            if ident == "multiple_of" && key == "epsilon" && is_eq(eq) =>
+                        {
+                            epsilon = Some(arg);
+                        }
+                        _ if value.is_none() => value = Some(arg),
+                        _ => {
+                            let msg = format!(
+                                "`{ident}` expects at most one value and an optional `message`."
+                            );
+                            return Err(syn::Error::new(group.span(), msg));
+                        }
+                    }
+                }
+
+                if !named_args.is_empty() && value.is_some() {
+                    let msg = "A named `with` validator expects `name`, `fn` and an optional `message`, e.g.:\n\n    with(name = NotReserved, fn = is_not_reserved)\n\n";
+                    return Err(syn::Error::new(group.span(), msg));
+                }
+                if let Some((message, span)) = message {
+                    // Named custom validators are distinguished by their names
+                    let key = match (named_args.is_empty(), name) {
+                        (true, _) => ident.to_string(),
+                        (false, Some(name)) => name,
+                        (false, None) => {
+                            let msg = "A named `with` validator requires `name`.";
+                            return Err(syn::Error::new(span, msg));
+                        }
+                    };
+                    messages.insert(key, message);
+                }
+
+                output.push(TokenTree::Ident(ident.clone()));
+                if !named_args.is_empty() {
+                    // Keep the named validator as it is (without the message),
+                    // it's parsed with `parse_named_custom_validator()`.
+                    let named_stream: TokenStream = named_args
+                        .join(&TokenTree::Punct(Punct::new(',', Spacing::Alone)))
+                        .into_iter()
+                        .collect();
+                    let mut named_group = Group::new(Delimiter::Parenthesis, named_stream);
+                    named_group.set_span(group.span());
+                    output.push(TokenTree::Group(named_group));
+                } else if let Some(epsilon) = epsilon {
+                    // Keep `multiple_of` with `epsilon` in parenthesis (without the message),
+                    // it's parsed with `parse_multiple_of()`.
+                    let args: Vec<&[TokenTree]> = value.into_iter().chain([epsilon]).collect();
+                    let args_stream: TokenStream = args
+                        .join(&TokenTree::Punct(Punct::new(',', Spacing::Alone)))
+                        .into_iter()
+                        .collect();
+                    let mut args_group = Group::new(Delimiter::Parenthesis, args_stream);
+                    args_group.set_span(group.span());
+                    output.push(TokenTree::Group(args_group));
+                } else if let Some(value) = value {
+                    output.push(TokenTree::Punct(Punct::new('=', Spacing::Alone)));
+                    output.extend(value.iter().cloned());
+                }
+            }
+            _ => output.extend(validator_tokens.iter().cloned()),
+        }
+        output.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+    }
+
+    Ok((output.into_iter().collect(), messages))
+}
+
 pub fn split_and_parse<SEP, PRS, OUT>(
     tokens: Vec<TokenTree>,
     is_separator: SEP,
@@ -189,6 +592,48 @@ pub fn parse_with_token_stream<'a>(
     Ok(rest)
 }
 
+/// Parses arguments of a named custom validator.
+///
+/// ## Example
+/// Input:
+///     (name = NotReserved, fn = is_not_reserved)
+/// Output:
+///     NamedCustomValidator { name: NotReserved, func: is_not_reserved }
+pub fn parse_named_custom_validator(
+    group: &Group,
+    with_span: Span,
+) -> Result<NamedCustomValidator, syn::Error> {
+    let syntax_error = |span: Span| {
+        let msg = "Invalid syntax for a named `with` validator. Expected:\n\n    with(name = NotReserved, fn = is_not_reserved)\n\n";
+        syn::Error::new(span, msg)
+    };
+
+    let tokens: Vec<TokenTree> = group.stream().into_iter().collect();
+    let mut name: Option<Ident> = None;
+    let mut func: Option<TokenStream> = None;
+
+    for arg in tokens.split(is_comma).filter(|arg| !arg.is_empty()) {
+        match arg {
+            [TokenTree::Ident(key), eq, TokenTree::Ident(value)]
+                if key == "name" && is_eq(eq) && name.is_none() =>
+            {
+                name = Some(value.clone());
+            }
+            [TokenTree::Ident(key), eq, rest @ ..]
+                if key == "fn" && is_eq(eq) && !rest.is_empty() && func.is_none() =>
+            {
+                func = Some(rest.iter().cloned().collect());
+            }
+            _ => return Err(syntax_error(arg[0].span())),
+        }
+    }
+
+    match (name, func) {
+        (Some(name), Some(func)) => Ok(NamedCustomValidator { name, func }),
+        _ => Err(syntax_error(with_span)),
+    }
+}
+
 pub fn is_doc_attribute(attribute: &syn::Attribute) -> bool {
     match attribute.path.segments.first() {
         Some(path_segment) => path_segment.ident == "doc",
@@ -274,11 +719,19 @@ fn parse_ident_into_derive_trait(ident: Ident) -> Result<SpannedDeriveTrait, syn
         "Ord" => NormalDeriveTrait::Ord,
         "FromStr" => NormalDeriveTrait::FromStr,
         "AsRef" => NormalDeriveTrait::AsRef,
+        "Deref" => NormalDeriveTrait::Deref,
+        "Add" => NormalDeriveTrait::Add,
+        "Sub" => NormalDeriveTrait::Sub,
+        "Mul" => NormalDeriveTrait::Mul,
+        "Div" => NormalDeriveTrait::Div,
+        "Neg" => NormalDeriveTrait::Neg,
+        "Sum" => NormalDeriveTrait::Sum,
         "TryFrom" => NormalDeriveTrait::TryFrom,
         "From" => NormalDeriveTrait::From,
         "Into" => NormalDeriveTrait::Into,
         "Hash" => NormalDeriveTrait::Hash,
         "Borrow" => NormalDeriveTrait::Borrow,
+        "Default" => NormalDeriveTrait::Default,
         "Serialize" => {
             #[cfg(not(feature = "serde1"))]
             return Err(syn::Error::new(ident.span(), "To derive Serialize, the feature `serde1` of the crate `nutype` needs to be enabled."));
@@ -293,6 +746,23 @@ fn parse_ident_into_derive_trait(ident: Ident) -> Result<SpannedDeriveTrait, syn
             #[cfg(feature = "serde1")]
             NormalDeriveTrait::SerdeDeserialize
         }
+        "JsonSchema" => {
+            #[cfg(not(feature = "schemars"))]
+            return Err(syn::Error::new(ident.span(), "To derive JsonSchema, the feature `schemars` of the crate `nutype` needs to be enabled."));
+
+            #[cfg(feature = "schemars")]
+            NormalDeriveTrait::SchemarsJsonSchema
+        }
+        "SqlxType" => {
+            #[cfg(not(feature = "sqlx"))]
+            return Err(syn::Error::new(
+                ident.span(),
+                "To derive SqlxType, the feature `sqlx` of the crate `nutype` needs to be enabled.",
+            ));
+
+            #[cfg(feature = "sqlx")]
+            NormalDeriveTrait::SqlxType
+        }
         _ => {
             return Err(syn::Error::new(
                 ident.span(),
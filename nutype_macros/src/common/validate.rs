@@ -1,7 +1,9 @@
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span};
 use syn::spanned::Spanned;
 
-use crate::common::models::Kind;
+use crate::common::models::{
+    Attributes, DeriveTrait, Kind, NormalDeriveTrait, RawGuard, SpannedDeriveTrait, SpannedItem,
+};
 
 pub fn validate_duplicates<T>(
     items: &[T],
@@ -35,3 +37,105 @@ fn detect_items_of_same_kind<T: Kind>(items: &[T]) -> Option<(&T, &T)> {
 fn join_spans_or_last(span1: Span, span2: Span) -> Span {
     span1.join(span2).unwrap_or(span2)
 }
+
+/// A named custom validator becomes a variant of the generated error type,
+/// so its name must not clash with the variant of any other validator.
+/// Two named validators with the same name are rejected earlier as duplicates.
+pub fn validate_error_variant_names<V>(
+    validators: &[SpannedItem<V>],
+    error_variant_name: impl Fn(&V) -> Ident,
+    named_validator_name: impl Fn(&V) -> Option<&Ident>,
+) -> Result<(), syn::Error>
+where
+    V: Kind,
+    <V as Kind>::Kind: std::fmt::Display,
+{
+    for (i1, validator) in validators.iter().enumerate() {
+        let Some(name) = named_validator_name(&validator.item) else {
+            continue;
+        };
+        let maybe_other = validators
+            .iter()
+            .enumerate()
+            .find(|(i2, other)| i1 != *i2 && error_variant_name(&other.item) == *name);
+        if let Some((_, other)) = maybe_other {
+            let msg = format!(
+                "Error variant `{name}` is already generated for `{}` validator.\nPlease, choose another name.",
+                other.kind()
+            );
+            return Err(syn::Error::new(name.span(), msg));
+        }
+    }
+    Ok(())
+}
+
+/// Custom error type (`error = MyError`) can be used only with `with` validators,
+/// because the other validators return variants of the generated error type.
+pub fn validate_error_options<S, V>(
+    attributes: &Attributes<RawGuard<S, V>>,
+    is_with_validator: impl Fn(&V) -> bool,
+) -> Result<(), syn::Error>
+where
+    V: Spanned,
+{
+    let error_options = &attributes.error_options;
+    let Some(custom_type) = &error_options.custom_type else {
+        return Ok(());
+    };
+    let validators = &attributes.guard.validators;
+
+    if let Some(validator) = validators.iter().find(|v| !is_with_validator(v)) {
+        let msg = "Only `with` validators can be used together with a custom error type (`error = ...`).\nThe other validators report errors of the generated error type.";
+        return Err(syn::Error::new(validator.span(), msg));
+    }
+    if validators.is_empty() {
+        let msg = "A custom error type (`error = ...`) requires at least one `with` validator, that returns `Result<(), YourError>`.";
+        return Err(syn::Error::new(custom_type.span(), msg));
+    }
+    if error_options.detailed {
+        let msg = "`detailed_errors` has no effect with a custom error type (`error = ...`).";
+        return Err(syn::Error::new(custom_type.span(), msg));
+    }
+    if !error_options.messages.is_empty() {
+        let msg = "Custom error messages (`message = ...`) have no effect with a custom error type (`error = ...`).";
+        return Err(syn::Error::new(custom_type.span(), msg));
+    }
+
+    Ok(())
+}
+
+/// `Default` trait is implemented with the value set by `default = ...`,
+/// so they make sense only together.
+pub fn validate_default_value<G>(
+    derive_traits: &[SpannedDeriveTrait],
+    attributes: &Attributes<G>,
+) -> Result<(), syn::Error> {
+    let maybe_default_trait = derive_traits
+        .iter()
+        .find(|t| t.item == DeriveTrait::Normal(NormalDeriveTrait::Default));
+
+    match (maybe_default_trait, &attributes.default) {
+        (Some(default_trait), None) => {
+            let msg = "#[nutype] cannot derive `Default` without a default value.\nSet it with `default = ...`, e.g.:\n\n    #[nutype(default = 18, validate(min = 18))]\n\n";
+            Err(syn::Error::new(default_trait.span, msg))
+        }
+        (None, Some(default_value)) => {
+            let msg = "The default value has no effect without `#[derive(Default)]`.";
+            Err(syn::Error::new(default_value.span(), msg))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns an error for a default value, that is known at compile time to violate a validator.
+pub fn gen_default_value_error<V>(default_value: &syn::Expr, validator: &V) -> syn::Error
+where
+    V: Kind,
+    <V as Kind>::Kind: std::fmt::Display,
+{
+    let msg = format!(
+        "The default value violates `{}` validator.",
+        validator.kind()
+    );
+    syn::Error::new(default_value.span(), msg)
+}
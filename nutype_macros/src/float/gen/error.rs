@@ -1,24 +1,37 @@
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 
 use crate::common::{
-    gen::error::{gen_error_type_name, gen_impl_error_trait},
-    models::TypeName,
+    gen::error::{
+        gen_bound_error_variant, gen_error_type_name, gen_impl_error_trait,
+        gen_named_validator_message,
+    },
+    models::{ErrorOptions, FloatType, NamedCustomValidator, TypeName},
 };
 
 use super::super::models::FloatValidator;
 
 pub fn gen_validation_error_type<T>(
     type_name: &TypeName,
+    inner_type: FloatType,
     validators: &[FloatValidator<T>],
+    error_options: &ErrorOptions,
 ) -> TokenStream {
+    let detailed_errors = error_options.detailed;
     let error_type_name = gen_error_type_name(type_name);
-    let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(&error_type_name, validators);
+    let definition = gen_definition(&error_type_name, inner_type, validators, detailed_errors);
+    let impl_display_trait = gen_impl_display_trait(&error_type_name, validators, error_options);
     let impl_error_trait = gen_impl_error_trait(&error_type_name);
 
+    // Floats do not implement `Eq`, so errors that carry them can't implement it either.
+    let derive_eq = if detailed_errors {
+        None
+    } else {
+        Some(quote!(Eq))
+    };
+
     quote! {
-        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[derive(Debug, Clone, PartialEq, #derive_eq)]
         #definition
 
         #impl_display_trait
@@ -26,19 +39,42 @@ pub fn gen_validation_error_type<T>(
     }
 }
 
-fn gen_definition<T>(error_type_name: &Ident, validators: &[FloatValidator<T>]) -> TokenStream {
+fn gen_definition<T>(
+    error_type_name: &Ident,
+    inner_type: FloatType,
+    validators: &[FloatValidator<T>],
+    detailed_errors: bool,
+) -> TokenStream {
     let error_variants: TokenStream = validators
         .iter()
         .map(|validator| match validator {
             FloatValidator::Min(_) => {
-                quote!(TooSmall,)
+                gen_bound_error_variant("TooSmall", "min", inner_type, detailed_errors)
             }
             FloatValidator::Max(_) => {
-                quote!(TooBig,)
+                gen_bound_error_variant("TooBig", "max", inner_type, detailed_errors)
+            }
+            FloatValidator::Greater(_) => {
+                gen_bound_error_variant("TooSmall", "greater", inner_type, detailed_errors)
+            }
+            FloatValidator::Less(_) => {
+                gen_bound_error_variant("TooBig", "less", inner_type, detailed_errors)
+            }
+            FloatValidator::MultipleOf { .. } => {
+                gen_bound_error_variant("NotMultipleOf", "multiple_of", inner_type, detailed_errors)
+            }
+            FloatValidator::Finite => {
+                quote!(NotFinite,)
+            }
+            FloatValidator::NotNan => {
+                quote!(NaN,)
             }
             FloatValidator::With(_) => {
                 quote!(Invalid,)
             }
+            FloatValidator::NamedWith(NamedCustomValidator { name, .. }) => {
+                quote!(#name,)
+            }
         })
         .collect();
 
@@ -52,17 +88,58 @@ fn gen_definition<T>(error_type_name: &Ident, validators: &[FloatValidator<T>])
 fn gen_impl_display_trait<T>(
     error_type_name: &Ident,
     validators: &[FloatValidator<T>],
+    error_options: &ErrorOptions,
 ) -> TokenStream {
-    let match_arms = validators.iter().map(|validator| match validator {
-        FloatValidator::Min(_) => quote! {
+    let detailed_errors = error_options.detailed;
+    let match_arms = validators.iter().map(|validator| {
+        if let Some(message) = error_options.message_for(validator) {
+            let variant = error_variant_name(validator);
+            return quote! {
+                #error_type_name::#variant { .. } => f.write_str(#message)
+            };
+        }
+
+        match validator {
+        FloatValidator::Min(_) if detailed_errors => quote! {
+             #error_type_name::TooSmall { min, actual } => write!(f, "too small: expected at least {min}, got {actual}")
+        },
+        FloatValidator::Max(_) if detailed_errors => quote! {
+             #error_type_name::TooBig { max, actual } => write!(f, "too big: expected at most {max}, got {actual}")
+        },
+        FloatValidator::Greater(_) if detailed_errors => quote! {
+             #error_type_name::TooSmall { greater, actual } => write!(f, "too small: expected greater than {greater}, got {actual}")
+        },
+        FloatValidator::Less(_) if detailed_errors => quote! {
+             #error_type_name::TooBig { less, actual } => write!(f, "too big: expected less than {less}, got {actual}")
+        },
+        FloatValidator::MultipleOf { .. } if detailed_errors => quote! {
+             #error_type_name::NotMultipleOf { multiple_of, actual } => write!(f, "not a multiple: expected a multiple of {multiple_of}, got {actual}")
+        },
+        FloatValidator::Min(_) | FloatValidator::Greater(_) => quote! {
              #error_type_name::TooSmall => write!(f, "too small")
         },
-        FloatValidator::Max(_) => quote! {
+        FloatValidator::Max(_) | FloatValidator::Less(_) => quote! {
              #error_type_name::TooBig=> write!(f, "too big")
         },
+        FloatValidator::MultipleOf { .. } => quote! {
+             #error_type_name::NotMultipleOf => write!(f, "not a multiple")
+        },
+        FloatValidator::Finite => quote! {
+             #error_type_name::NotFinite => write!(f, "not finite")
+        },
+        FloatValidator::NotNan => quote! {
+             #error_type_name::NaN => write!(f, "NaN")
+        },
         FloatValidator::With(_) => quote! {
              #error_type_name::Invalid => write!(f, "invalid")
         },
+        FloatValidator::NamedWith(NamedCustomValidator { name, .. }) => {
+            let message = gen_named_validator_message(name);
+            quote! {
+                 #error_type_name::#name => f.write_str(#message)
+            }
+        }
+    }
     });
 
     quote! {
@@ -75,3 +152,16 @@ fn gen_impl_display_trait<T>(
         }
     }
 }
+
+pub fn error_variant_name<T>(validator: &FloatValidator<T>) -> Ident {
+    let name = match validator {
+        FloatValidator::Min(_) | FloatValidator::Greater(_) => "TooSmall",
+        FloatValidator::Max(_) | FloatValidator::Less(_) => "TooBig",
+        FloatValidator::MultipleOf { .. } => "NotMultipleOf",
+        FloatValidator::Finite => "NotFinite",
+        FloatValidator::NotNan => "NaN",
+        FloatValidator::With(_) => "Invalid",
+        FloatValidator::NamedWith(NamedCustomValidator { name, .. }) => return name.clone(),
+    };
+    Ident::new(name, Span::call_site())
+}
@@ -5,36 +5,63 @@ use std::collections::HashSet;
 
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
-use syn::Visibility;
+use syn::{Generics, Visibility};
 
 use self::error::gen_validation_error_type;
 use super::models::{FloatDeriveTrait, FloatGuard, FloatSanitizer, FloatValidator};
 use crate::{
     common::gen::{
-        error::gen_error_type_name, gen_module_name_for_type, gen_reimports,
-        parse_error::gen_parse_error_name, traits::GeneratedTraits, type_custom_closure,
+        error::{gen_bound_error, gen_error_type_name, gen_error_type_path},
+        gen_const_check, gen_constraint_rule, gen_custom_validator_check, gen_impl_constraints,
+        gen_impl_nutype_trait, gen_module_name_for_type, gen_named_custom_validator_check,
+        gen_new_const, gen_new_unchecked, gen_reimports, gen_report_error,
+        gen_sanitize_and_validate_fns, gen_validate_fn_from_checks,
+        parse_error::gen_parse_error_name,
+        traits::{gen_json_schema_refinements, GeneratedTraits, SaturationBounds},
+        type_custom_closure, ConstraintValue,
     },
-    common::models::{FloatType, TypeName},
+    common::models::{Attributes, ErrorOptions, FloatType, Kind, NamedCustomValidator, TypeName},
 };
-use traits::gen_traits;
+use traits::{gen_json_schema_constraints, gen_traits};
 
 pub fn gen_nutype_for_float<T>(
     doc_attrs: Vec<syn::Attribute>,
     vis: Visibility,
     inner_type: FloatType,
     type_name: &TypeName,
-    meta: FloatGuard<T>,
+    attributes: Attributes<FloatGuard<T>>,
     traits: HashSet<FloatDeriveTrait>,
 ) -> TokenStream
 where
     T: ToTokens + PartialOrd,
 {
+    let Attributes {
+        guard: meta,
+        error_options,
+        default,
+        new_unchecked,
+    } = attributes;
     let module_name = gen_module_name_for_type(type_name);
-    let implementation = gen_implementation(type_name, inner_type, &meta);
+    let implementation =
+        gen_implementation(type_name, inner_type, &meta, &error_options, new_unchecked);
 
-    let maybe_error_type_name: Option<Ident> = match meta {
+    let maybe_error_type_name: Option<syn::Path> = match meta {
         FloatGuard::WithoutValidation { .. } => None,
-        FloatGuard::WithValidation { .. } => Some(gen_error_type_name(type_name)),
+        FloatGuard::WithValidation { .. } => Some(gen_error_type_path(type_name, &error_options)),
+    };
+    let impl_nutype_trait = gen_impl_nutype_trait(
+        type_name,
+        inner_type,
+        &Generics::default(),
+        maybe_error_type_name.as_ref(),
+    );
+
+    // A custom error type is defined by the user, so only the generated one needs to be reimported.
+    let maybe_reimported_error_type_name: Option<Ident> = match meta {
+        FloatGuard::WithValidation { .. } if error_options.custom_type.is_none() => {
+            Some(gen_error_type_name(type_name))
+        }
+        _ => None,
     };
 
     let maybe_parse_error_type_name = if traits.contains(&FloatDeriveTrait::FromStr) {
@@ -47,14 +74,22 @@ where
         vis,
         type_name,
         &module_name,
-        maybe_error_type_name.as_ref(),
+        maybe_reimported_error_type_name.as_ref(),
         maybe_parse_error_type_name.as_ref(),
     );
 
     let GeneratedTraits {
         derive_standard_traits,
         implement_traits,
-    } = gen_traits(type_name, inner_type, maybe_error_type_name, traits);
+    } = gen_traits(
+        type_name,
+        inner_type,
+        maybe_error_type_name,
+        default,
+        gen_saturation_bounds(inner_type, &meta),
+        gen_json_schema_refinements(&doc_attrs, gen_json_schema_constraints(&meta)),
+        traits,
+    );
 
     quote!(
         #[doc(hidden)]
@@ -66,37 +101,213 @@ where
             pub struct #type_name(#inner_type);
 
             #implementation
+            #impl_nutype_trait
             #implement_traits
         }
         #reimports
     )
 }
 
+/// Values can be clamped into a valid range only if there are no sanitizers, that could change
+/// the clamped value, and the validators are limited to `min`, `max` and `finite`.
+/// Clamping to the finite range of the inner type gets rid of infinities. Addition, subtraction
+/// and multiplication of finite values never produce NaN, but `0.0 / 0.0` does, so `saturating_div`
+/// is not available when NaN is invalid.
+fn gen_saturation_bounds<T>(inner_type: FloatType, meta: &FloatGuard<T>) -> Option<SaturationBounds>
+where
+    T: ToTokens,
+{
+    match meta {
+        FloatGuard::WithoutValidation { .. } => Some(SaturationBounds {
+            min: None,
+            max: None,
+            clamps_div: true,
+        }),
+        FloatGuard::WithValidation {
+            sanitizers,
+            validators,
+        } => {
+            if !sanitizers.is_empty() {
+                return None;
+            }
+            let mut maybe_min = None;
+            let mut maybe_max = None;
+            let mut is_finite = false;
+            let mut is_not_nan = false;
+            for validator in validators {
+                match validator {
+                    FloatValidator::Min(min) => maybe_min = Some(quote!(#min)),
+                    FloatValidator::Max(max) => maybe_max = Some(quote!(#max)),
+                    FloatValidator::Finite => is_finite = true,
+                    FloatValidator::NotNan => is_not_nan = true,
+                    FloatValidator::Greater(_)
+                    | FloatValidator::Less(_)
+                    | FloatValidator::MultipleOf { .. }
+                    | FloatValidator::With(_)
+                    | FloatValidator::NamedWith(_) => return None,
+                }
+            }
+            if is_not_nan && !is_finite {
+                // Infinities of opposite signs produce NaN when added
+                return None;
+            }
+            if is_finite {
+                maybe_min = maybe_min.or_else(|| Some(quote!(#inner_type::MIN)));
+                maybe_max = maybe_max.or_else(|| Some(quote!(#inner_type::MAX)));
+            }
+            Some(SaturationBounds {
+                min: maybe_min,
+                max: maybe_max,
+                clamps_div: !is_finite && !is_not_nan,
+            })
+        }
+    }
+}
+
 pub fn gen_implementation<T>(
     type_name: &TypeName,
     inner_type: FloatType,
     meta: &FloatGuard<T>,
+    error_options: &ErrorOptions,
+    new_unchecked: bool,
 ) -> TokenStream
 where
     T: ToTokens + PartialOrd,
 {
     let convert_implementation = match meta {
         FloatGuard::WithoutValidation { sanitizers } => {
-            gen_new_without_validation(type_name, inner_type, sanitizers)
+            gen_new_without_validation(type_name, inner_type, sanitizers, new_unchecked)
         }
         FloatGuard::WithValidation {
             sanitizers,
             validators,
-        } => gen_new_with_validation(type_name, inner_type, sanitizers, validators),
+        } => gen_new_with_validation(
+            type_name,
+            inner_type,
+            sanitizers,
+            validators,
+            error_options,
+            new_unchecked,
+        ),
     };
+    let new_const = gen_new_const_if_possible(type_name, inner_type, meta);
+    let constraints = gen_constraints(type_name, inner_type, meta);
     let methods = gen_impl_methods(type_name, inner_type);
 
     quote! {
         #convert_implementation
+        #new_const
+        #constraints
         #methods
     }
 }
 
+fn gen_constraints<T>(
+    type_name: &TypeName,
+    inner_type: FloatType,
+    meta: &FloatGuard<T>,
+) -> TokenStream
+where
+    T: ToTokens,
+{
+    let value = ConstraintValue::Float;
+    let sanitizers = meta
+        .sanitizers()
+        .iter()
+        .map(|sanitizer| match sanitizer {
+            FloatSanitizer::Clamp { min, max } => {
+                gen_constraint_rule(sanitizer.kind(), &[value.gen(min), value.gen(max)])
+            }
+            FloatSanitizer::Round(digits) => {
+                gen_constraint_rule(sanitizer.kind(), &[ConstraintValue::UInt.gen(digits)])
+            }
+            FloatSanitizer::ReplaceNan(replacement) => {
+                gen_constraint_rule(sanitizer.kind(), &[value.gen(replacement)])
+            }
+            FloatSanitizer::Floor
+            | FloatSanitizer::Ceil
+            | FloatSanitizer::Trunc
+            | FloatSanitizer::Abs
+            | FloatSanitizer::With(_) => gen_constraint_rule(sanitizer.kind(), &[]),
+        })
+        .collect();
+    let validators = meta
+        .validators()
+        .iter()
+        .map(|validator| match validator {
+            FloatValidator::Min(bound)
+            | FloatValidator::Max(bound)
+            | FloatValidator::Greater(bound)
+            | FloatValidator::Less(bound) => {
+                gen_constraint_rule(validator.kind(), &[value.gen(bound)])
+            }
+            FloatValidator::MultipleOf { step, epsilon } => {
+                let args: Vec<_> = std::iter::once(step)
+                    .chain(epsilon)
+                    .map(|arg| value.gen(arg))
+                    .collect();
+                gen_constraint_rule(validator.kind(), &args)
+            }
+            FloatValidator::Finite | FloatValidator::NotNan | FloatValidator::With(_) => {
+                gen_constraint_rule(validator.kind(), &[])
+            }
+            FloatValidator::NamedWith(NamedCustomValidator { name, .. }) => {
+                gen_constraint_rule("with", &[ConstraintValue::Str.gen(name.to_string())])
+            }
+        })
+        .collect();
+    gen_impl_constraints(
+        type_name,
+        inner_type,
+        &Generics::default(),
+        sanitizers,
+        validators,
+    )
+}
+
+/// `new_const()` is generated only if a value can be checked in a const context:
+/// there are no sanitizers and all the validators are built-in.
+fn gen_new_const_if_possible<T>(
+    type_name: &TypeName,
+    inner_type: FloatType,
+    meta: &FloatGuard<T>,
+) -> TokenStream
+where
+    T: ToTokens,
+{
+    let validators: &[FloatValidator<T>] = match meta {
+        FloatGuard::WithoutValidation { sanitizers } if sanitizers.is_empty() => &[],
+        FloatGuard::WithValidation {
+            sanitizers,
+            validators,
+        } if sanitizers.is_empty() => validators,
+        _ => return quote!(),
+    };
+    let maybe_checks: Option<TokenStream> = validators
+        .iter()
+        .map(|validator| {
+            let violation = match validator {
+                FloatValidator::Min(min) => quote!(val < #min),
+                FloatValidator::Max(max) => quote!(val > #max),
+                FloatValidator::Greater(greater) => quote!(val <= #greater),
+                FloatValidator::Less(less) => quote!(val >= #less),
+                FloatValidator::Finite => quote!(!val.is_finite()),
+                FloatValidator::NotNan => quote!(val.is_nan()),
+                // `round()` is not const. Skipping the check would let `new_const()` accept values
+                // rejected by `new()`, so no `new_const()` is generated at all.
+                FloatValidator::MultipleOf { .. }
+                | FloatValidator::With(_)
+                | FloatValidator::NamedWith(_) => return None,
+            };
+            Some(gen_const_check(type_name, violation, validator.kind()))
+        })
+        .collect();
+    match maybe_checks {
+        Some(checks) => gen_new_const(type_name, inner_type, checks),
+        None => quote!(),
+    }
+}
+
 fn gen_impl_methods(type_name: &TypeName, inner_type: FloatType) -> TokenStream {
     quote! {
         impl #type_name {
@@ -111,18 +322,41 @@ fn gen_new_without_validation<T>(
     type_name: &TypeName,
     inner_type: FloatType,
     sanitizers: &[FloatSanitizer<T>],
+    new_unchecked: bool,
 ) -> TokenStream
 where
     T: ToTokens + PartialOrd,
 {
     let sanitize = gen_sanitize_fn(inner_type, sanitizers);
+    let new_unchecked = if new_unchecked {
+        gen_new_unchecked(
+            type_name,
+            quote!(raw_value: #inner_type),
+            quote!(raw_value),
+            false,
+        )
+    } else {
+        quote!()
+    };
+
+    let sanitize_and_validate = gen_sanitize_and_validate_fns(
+        quote!(#inner_type),
+        quote!(raw_value: #inner_type),
+        quote!(raw_value),
+        quote!(&#inner_type),
+        sanitize,
+        None,
+    );
 
     quote!(
         impl #type_name {
             pub fn new(raw_value: #inner_type) -> Self {
-                #sanitize
-                Self(sanitize(raw_value))
+                Self(Self::sanitize(raw_value))
             }
+
+            #sanitize_and_validate
+
+            #new_unchecked
         }
     )
 }
@@ -132,29 +366,71 @@ fn gen_new_with_validation<T>(
     inner_type: FloatType,
     sanitizers: &[FloatSanitizer<T>],
     validators: &[FloatValidator<T>],
+    error_options: &ErrorOptions,
+    new_unchecked: bool,
 ) -> TokenStream
 where
     T: ToTokens + PartialOrd,
 {
     let sanitize = gen_sanitize_fn(inner_type, sanitizers);
-    let validation_error = gen_validation_error_type(type_name, validators);
-    let error_type_name = gen_error_type_name(type_name);
-    let validate = gen_validate_fn(type_name, inner_type, validators);
+    let validation_error = if error_options.custom_type.is_none() {
+        gen_validation_error_type(type_name, inner_type, validators, error_options)
+    } else {
+        quote!()
+    };
+    let error_type_name = gen_error_type_path(type_name, error_options);
+    let validate = gen_validate_fn(type_name, inner_type, validators, error_options, false);
+
+    let new_collecting = if error_options.collect {
+        let validate_all = gen_validate_fn(type_name, inner_type, validators, error_options, true);
+        quote!(
+            /// Same as `new()`, but instead of stopping at the first error, returns all the violated validation rules.
+            pub fn new_collecting(raw_value: #inner_type) -> ::core::result::Result<Self, ::std::vec::Vec<#error_type_name>> {
+                #validate_all
+
+                let sanitized_value = Self::sanitize(raw_value);
+                validate_all(sanitized_value)?;
+                Ok(#type_name(sanitized_value))
+            }
+        )
+    } else {
+        quote!()
+    };
+
+    let new_unchecked = if new_unchecked {
+        gen_new_unchecked(
+            type_name,
+            quote!(raw_value: #inner_type),
+            quote!(raw_value),
+            true,
+        )
+    } else {
+        quote!()
+    };
+
+    let sanitize_and_validate = gen_sanitize_and_validate_fns(
+        quote!(#inner_type),
+        quote!(raw_value: #inner_type),
+        quote!(raw_value),
+        quote!(&#inner_type),
+        sanitize,
+        Some((&error_type_name, quote!(#validate validate(*value)))),
+    );
 
     quote!(
         #validation_error
 
         impl #type_name {
             pub fn new(raw_value: #inner_type) -> ::core::result::Result<Self, #error_type_name> {
-                // Keep sanitize() and validate() within new() so they do not overlap with outer
-                // scope imported with `use super::*`.
-                #sanitize
-                #validate
-
-                let sanitized_value = sanitize(raw_value);
-                validate(sanitized_value)?;
+                let sanitized_value = Self::sanitize(raw_value);
+                Self::validate_sanitized(&sanitized_value)?;
                 Ok(#type_name(sanitized_value))
             }
+
+            #sanitize_and_validate
+
+            #new_collecting
+            #new_unchecked
         }
     )
 }
@@ -166,15 +442,50 @@ where
     let transformations: TokenStream = sanitizers
         .iter()
         .map(|san| match san {
+            FloatSanitizer::Clamp { min, max } => quote!(
+                value = value.clamp(#min, #max);
+            ),
+            FloatSanitizer::Round(0) => quote!(
+                value = value.round();
+            ),
+            FloatSanitizer::Round(decimals) => {
+                let decimals = *decimals as i32;
+                quote!(
+                    value = {
+                        let factor = <#inner_type>::powi(10.0, #decimals);
+                        let scaled = value * factor;
+                        // A value, that overflows when scaled, is too big to have decimal places.
+                        if scaled.is_finite() {
+                            scaled.round() / factor
+                        } else {
+                            value
+                        }
+                    };
+                )
+            }
+            FloatSanitizer::Floor => quote!(
+                value = value.floor();
+            ),
+            FloatSanitizer::Ceil => quote!(
+                value = value.ceil();
+            ),
+            FloatSanitizer::Trunc => quote!(
+                value = value.trunc();
+            ),
+            FloatSanitizer::Abs => quote!(
+                value = value.abs();
+            ),
+            FloatSanitizer::ReplaceNan(replacement) => quote!(
+                if value.is_nan() {
+                    value = #replacement;
+                }
+            ),
             FloatSanitizer::With(token_stream) => {
                 let custom_sanitizer = type_custom_closure(token_stream, inner_type);
                 quote!(
                     value = (#custom_sanitizer)(value);
                 )
             }
-            FloatSanitizer::_Phantom(_) => {
-                unreachable!("float::gen FloatSanitizer::_Phantom must not be used")
-            }
         })
         .collect();
 
@@ -190,45 +501,115 @@ fn gen_validate_fn<T>(
     type_name: &TypeName,
     inner_type: FloatType,
     validators: &[FloatValidator<T>],
+    error_options: &ErrorOptions,
+    collect: bool,
 ) -> TokenStream
 where
     T: ToTokens + PartialOrd,
 {
-    let error_name = gen_error_type_name(type_name);
+    let error_name = gen_error_type_path(type_name, error_options);
+    let detailed_errors = error_options.detailed;
+    let report_error = |error| gen_report_error(error, collect);
+    let bound_error = |variant, bound_name, bound: &T| {
+        gen_bound_error(
+            &error_name,
+            variant,
+            bound_name,
+            bound,
+            quote!(val),
+            detailed_errors,
+        )
+    };
 
     let validations: TokenStream = validators
         .iter()
         .map(|validator| match validator {
             FloatValidator::Max(max) => {
+                let error = bound_error("TooBig", "max", max);
+                let report = report_error(error);
                 quote!(
                     if val > #max {
-                        return Err(#error_name::TooBig);
+                        #report
                     }
                 )
             }
             FloatValidator::Min(min) => {
+                let error = bound_error("TooSmall", "min", min);
+                let report = report_error(error);
                 quote!(
                     if val < #min {
-                        return Err(#error_name::TooSmall);
+                        #report
                     }
                 )
             }
-            FloatValidator::With(is_valid_fn) => {
-                let inner_type_ref = quote!(&#inner_type);
-                let is_valid_fn = type_custom_closure(is_valid_fn, inner_type_ref);
+            FloatValidator::Greater(greater) => {
+                let error = bound_error("TooSmall", "greater", greater);
+                let report = report_error(error);
                 quote!(
-                    if !(#is_valid_fn)(&val) {
-                        return Err(#error_name::Invalid);
+                    if val <= #greater {
+                        #report
                     }
                 )
             }
+            FloatValidator::Less(less) => {
+                let error = bound_error("TooBig", "less", less);
+                let report = report_error(error);
+                quote!(
+                    if val >= #less {
+                        #report
+                    }
+                )
+            }
+            FloatValidator::MultipleOf { step, epsilon } => {
+                let error = bound_error("NotMultipleOf", "multiple_of", step);
+                let report = report_error(error);
+                // By default the tolerance grows with the value, since the rounding error
+                // of `val / step` and `n * step` is proportional to the magnitude of the value.
+                let tolerance = match epsilon {
+                    Some(epsilon) => quote!(#epsilon),
+                    None => quote!(4.0 * #inner_type::EPSILON * val.abs().max(#step)),
+                };
+                // NaN and infinities are not multiples of anything, while the comparison
+                // below would be false for them.
+                quote!(
+                    if !val.is_finite() || (val - (val / #step).round() * #step).abs() > #tolerance {
+                        #report
+                    }
+                )
+            }
+            FloatValidator::Finite => {
+                let report = report_error(quote!(#error_name::NotFinite));
+                quote!(
+                    if !val.is_finite() {
+                        #report
+                    }
+                )
+            }
+            FloatValidator::NotNan => {
+                let report = report_error(quote!(#error_name::NaN));
+                quote!(
+                    if val.is_nan() {
+                        #report
+                    }
+                )
+            }
+            FloatValidator::With(is_valid_fn) => {
+                let inner_type_ref = quote!(&#inner_type);
+                let is_valid_fn = type_custom_closure(is_valid_fn, inner_type_ref);
+                gen_custom_validator_check(is_valid_fn, &error_name, error_options, collect)
+            }
+            FloatValidator::NamedWith(NamedCustomValidator { name, func }) => {
+                let is_valid_fn = type_custom_closure(func, quote!(&#inner_type));
+                gen_named_custom_validator_check(is_valid_fn, &error_name, name, collect)
+            }
         })
         .collect();
 
-    quote!(
-        fn validate(val: #inner_type) -> core::result::Result<(), #error_name> {
-            #validations
-            Ok(())
-        }
+    gen_validate_fn_from_checks(
+        quote!(val: #inner_type),
+        &Generics::default(),
+        &error_name,
+        validations,
+        collect,
     )
 }
@@ -1,17 +1,20 @@
 use std::collections::HashSet;
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use crate::{
     common::gen::traits::{
-        gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_dislpay, gen_impl_trait_from,
-        gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_serde_deserialize,
-        gen_impl_trait_serde_serialize, gen_impl_trait_try_from, split_into_generatable_traits,
-        GeneratableTrait, GeneratableTraits, GeneratedTraits,
+        gen_arithmetic, gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
+        gen_impl_trait_deref, gen_impl_trait_dislpay, gen_impl_trait_from, gen_impl_trait_from_str,
+        gen_impl_trait_into, gen_impl_trait_neg, gen_impl_trait_schemars_json_schema,
+        gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize, gen_impl_trait_sqlx_type,
+        gen_impl_trait_sum, gen_impl_trait_try_from, split_into_generatable_traits,
+        ArithmeticOperator, GeneratableTrait, GeneratableTraits, GeneratedTraits, SaturationBounds,
     },
     common::models::{FloatType, TypeName},
-    float::models::FloatDeriveTrait,
+    float::models::{FloatDeriveTrait, FloatGuard, FloatValidator},
 };
 
 type FloatGeneratableTrait = GeneratableTrait<FloatStandardTrait, FloatIrregularTrait>;
@@ -32,13 +35,23 @@ enum FloatStandardTrait {
 enum FloatIrregularTrait {
     FromStr,
     AsRef,
+    Deref,
     Into,
     From,
     TryFrom,
     Borrow,
     Display,
+    Default,
+    Arithmetic(ArithmeticOperator),
+    Neg,
+    Sum,
+    Eq,
+    Ord,
+    Hash,
     SerdeSerialize,
     SerdeDeserialize,
+    SchemarsJsonSchema,
+    SqlxType,
 }
 
 impl From<FloatDeriveTrait> for FloatGeneratableTrait {
@@ -57,6 +70,7 @@ impl From<FloatDeriveTrait> for FloatGeneratableTrait {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::FromStr)
             }
             FloatDeriveTrait::AsRef => FloatGeneratableTrait::Irregular(FloatIrregularTrait::AsRef),
+            FloatDeriveTrait::Deref => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Deref),
             FloatDeriveTrait::From => FloatGeneratableTrait::Irregular(FloatIrregularTrait::From),
             FloatDeriveTrait::Into => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Into),
             FloatDeriveTrait::TryFrom => {
@@ -65,15 +79,41 @@ impl From<FloatDeriveTrait> for FloatGeneratableTrait {
             FloatDeriveTrait::Borrow => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::Borrow)
             }
+            FloatDeriveTrait::Default => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::Default)
+            }
+            FloatDeriveTrait::Add => FloatGeneratableTrait::Irregular(
+                FloatIrregularTrait::Arithmetic(ArithmeticOperator::Add),
+            ),
+            FloatDeriveTrait::Sub => FloatGeneratableTrait::Irregular(
+                FloatIrregularTrait::Arithmetic(ArithmeticOperator::Sub),
+            ),
+            FloatDeriveTrait::Mul => FloatGeneratableTrait::Irregular(
+                FloatIrregularTrait::Arithmetic(ArithmeticOperator::Mul),
+            ),
+            FloatDeriveTrait::Div => FloatGeneratableTrait::Irregular(
+                FloatIrregularTrait::Arithmetic(ArithmeticOperator::Div),
+            ),
+            FloatDeriveTrait::Neg => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Neg),
+            FloatDeriveTrait::Sum => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Sum),
             FloatDeriveTrait::Display => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::Display)
             }
+            FloatDeriveTrait::Eq => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Eq),
+            FloatDeriveTrait::Ord => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Ord),
+            FloatDeriveTrait::Hash => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Hash),
             FloatDeriveTrait::SerdeSerialize => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::SerdeSerialize)
             }
             FloatDeriveTrait::SerdeDeserialize => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::SerdeDeserialize)
             }
+            FloatDeriveTrait::SchemarsJsonSchema => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::SchemarsJsonSchema)
+            }
+            FloatDeriveTrait::SqlxType => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::SqlxType)
+            }
         }
     }
 }
@@ -94,9 +134,17 @@ impl ToTokens for FloatStandardTrait {
 pub fn gen_traits(
     type_name: &TypeName,
     inner_type: FloatType,
-    maybe_error_type_name: Option<Ident>,
-    traits: HashSet<FloatDeriveTrait>,
+    maybe_error_type_name: Option<syn::Path>,
+    maybe_default_value: Option<syn::Expr>,
+    maybe_saturation_bounds: Option<SaturationBounds>,
+    schema_refinements: TokenStream,
+    mut traits: HashSet<FloatDeriveTrait>,
 ) -> GeneratedTraits {
+    // When `Ord` is implemented manually, `PartialOrd` must be consistent with it,
+    // so it's implemented manually as well instead of being derived.
+    let implement_partial_ord =
+        traits.contains(&FloatDeriveTrait::Ord) && traits.remove(&FloatDeriveTrait::PartialOrd);
+
     let GeneratableTraits {
         standard_traits,
         irregular_traits,
@@ -108,12 +156,18 @@ pub fn gen_traits(
         )]
     };
 
-    let implement_traits = gen_implemented_traits(
+    let mut implement_traits = gen_implemented_traits(
         type_name,
         inner_type,
         maybe_error_type_name,
+        maybe_default_value,
+        maybe_saturation_bounds,
+        schema_refinements,
         irregular_traits,
     );
+    if implement_partial_ord {
+        implement_traits.extend(gen_impl_trait_partial_ord(type_name));
+    }
 
     GeneratedTraits {
         derive_standard_traits,
@@ -124,32 +178,159 @@ pub fn gen_traits(
 fn gen_implemented_traits(
     type_name: &TypeName,
     inner_type: FloatType,
-    maybe_error_type_name: Option<Ident>,
+    maybe_error_type_name: Option<syn::Path>,
+    maybe_default_value: Option<syn::Expr>,
+    maybe_saturation_bounds: Option<SaturationBounds>,
+    schema_refinements: TokenStream,
     impl_traits: Vec<FloatIrregularTrait>,
 ) -> TokenStream {
+    let generics = Generics::default();
     impl_traits
         .iter()
         .map(|t| match t {
-            FloatIrregularTrait::AsRef => gen_impl_trait_as_ref(type_name, inner_type),
+            FloatIrregularTrait::AsRef => gen_impl_trait_as_ref(type_name, &generics, inner_type),
+            FloatIrregularTrait::Deref => gen_impl_trait_deref(type_name, &generics, inner_type),
             FloatIrregularTrait::FromStr => {
                 gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref())
             }
-            FloatIrregularTrait::From => gen_impl_trait_from(type_name, inner_type),
-            FloatIrregularTrait::Into => gen_impl_trait_into(type_name, inner_type),
+            FloatIrregularTrait::From => gen_impl_trait_from(type_name, &generics, inner_type),
+            FloatIrregularTrait::Into => gen_impl_trait_into(type_name, &generics, inner_type),
             FloatIrregularTrait::TryFrom => {
                 let error_type_name = maybe_error_type_name
                     .as_ref()
                     .expect("TryFrom for float is expected to have error_type_name");
-                gen_impl_trait_try_from(type_name, inner_type, error_type_name)
+                gen_impl_trait_try_from(type_name, &generics, inner_type, error_type_name)
+            }
+            FloatIrregularTrait::Borrow => gen_impl_trait_borrow(type_name, &generics, inner_type),
+            FloatIrregularTrait::Default => {
+                let default_value = maybe_default_value
+                    .as_ref()
+                    .expect("Default trait is expected to have default value");
+                gen_impl_trait_default(
+                    type_name,
+                    &generics,
+                    default_value,
+                    maybe_error_type_name.is_some(),
+                )
+            }
+            FloatIrregularTrait::Display => gen_impl_trait_dislpay(type_name, &generics),
+            FloatIrregularTrait::Arithmetic(operator) => {
+                // Float operations never panic, infinity and NaN are handled by the validation.
+                let value = operator.apply(quote!(self.0), quote!(rhs.0));
+                gen_arithmetic(
+                    type_name,
+                    *operator,
+                    maybe_error_type_name.as_ref(),
+                    maybe_saturation_bounds.as_ref(),
+                    value.clone(),
+                    value.clone(),
+                    value,
+                )
+            }
+            FloatIrregularTrait::Neg => {
+                gen_impl_trait_neg(type_name, maybe_error_type_name.as_ref(), quote!(-self.0))
+            }
+            FloatIrregularTrait::Sum => gen_impl_trait_sum(
+                type_name,
+                maybe_error_type_name.as_ref(),
+                quote!(iter.map(|value| value.0).sum()),
+            ),
+            FloatIrregularTrait::Eq => gen_impl_trait_eq(type_name),
+            FloatIrregularTrait::Ord => gen_impl_trait_ord(type_name),
+            FloatIrregularTrait::Hash => gen_impl_trait_hash(type_name),
+            FloatIrregularTrait::SerdeSerialize => {
+                gen_impl_trait_serde_serialize(type_name, &generics)
             }
-            FloatIrregularTrait::Borrow => gen_impl_trait_borrow(type_name, inner_type),
-            FloatIrregularTrait::Display => gen_impl_trait_dislpay(type_name),
-            FloatIrregularTrait::SerdeSerialize => gen_impl_trait_serde_serialize(type_name),
             FloatIrregularTrait::SerdeDeserialize => gen_impl_trait_serde_deserialize(
                 type_name,
+                &generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            ),
+            FloatIrregularTrait::SchemarsJsonSchema => gen_impl_trait_schemars_json_schema(
+                type_name,
+                &generics,
+                inner_type,
+                &schema_refinements,
+            ),
+            FloatIrregularTrait::SqlxType => gen_impl_trait_sqlx_type(
+                type_name,
+                &generics,
                 inner_type,
                 maybe_error_type_name.as_ref(),
             ),
         })
         .collect()
 }
+
+// The traits below are allowed only when validation guarantees that the inner value is never NaN
+// (see `FloatGuard::excludes_nan()`), so the total order is well defined.
+
+fn gen_impl_trait_eq(type_name: &TypeName) -> TokenStream {
+    quote! {
+        impl ::core::cmp::Eq for #type_name { }
+    }
+}
+
+fn gen_impl_trait_ord(type_name: &TypeName) -> TokenStream {
+    quote! {
+        impl ::core::cmp::Ord for #type_name {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                self.0.partial_cmp(&other.0)
+                    .unwrap_or_else(|| {
+                        let tp = ::core::any::type_name::<Self>();
+                        panic!("{tp}::cmp() panicked, because partial_cmp() returned None. Could it be that the value is NaN?");
+                    })
+            }
+        }
+    }
+}
+
+fn gen_impl_trait_partial_ord(type_name: &TypeName) -> TokenStream {
+    quote! {
+        impl ::core::cmp::PartialOrd for #type_name {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+    }
+}
+
+fn gen_impl_trait_hash(type_name: &TypeName) -> TokenStream {
+    quote! {
+        impl ::core::hash::Hash for #type_name {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                // 0.0 and -0.0 are equal, so they must produce the same hash.
+                let value = if self.0 == 0.0 { 0.0 } else { self.0 };
+                ::core::hash::Hash::hash(&value.to_bits(), state);
+            }
+        }
+    }
+}
+
+/// Generate statements, that set `minimum`, `maximum`, etc. of the JSON schema according to the validators.
+pub fn gen_json_schema_constraints<T>(guard: &FloatGuard<T>) -> TokenStream
+where
+    T: ToTokens,
+{
+    guard
+        .validators()
+        .iter()
+        .filter_map(|validator| {
+            let (field, value) = match validator {
+                FloatValidator::Min(value) => (quote!(minimum), value),
+                FloatValidator::Max(value) => (quote!(maximum), value),
+                FloatValidator::Greater(value) => (quote!(exclusive_minimum), value),
+                FloatValidator::Less(value) => (quote!(exclusive_maximum), value),
+                FloatValidator::MultipleOf { step, .. } => (quote!(multiple_of), step),
+                FloatValidator::Finite
+                | FloatValidator::NotNan
+                | FloatValidator::With(_)
+                | FloatValidator::NamedWith(_) => return None,
+            };
+            Some(quote!(
+                schema_object.number().#field = ::core::option::Option::Some(#value as f64);
+            ))
+        })
+        .collect()
+}
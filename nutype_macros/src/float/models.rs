@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 
 use crate::{
     common::models::{Guard, RawGuard},
-    common::models::{Kind, SpannedItem},
+    common::models::{Kind, NamedCustomValidator, SpannedItem},
 };
 
 // Sanitizer
@@ -10,20 +10,44 @@ use crate::{
 
 #[derive(Debug)]
 pub enum FloatSanitizer<T> {
+    Clamp {
+        min: T,
+        max: T,
+    },
+    /// Rounds to the given number of decimal places.
+    Round(u32),
+    Floor,
+    Ceil,
+    Trunc,
+    Abs,
+    ReplaceNan(T),
     With(TokenStream),
-    _Phantom(std::marker::PhantomData<T>),
 }
 
 pub type SpannedFloatSanitizer<T> = SpannedItem<FloatSanitizer<T>>;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum FloatSanitizerKind {
+    Clamp,
+    Round,
+    Floor,
+    Ceil,
+    Trunc,
+    Abs,
+    ReplaceNan,
     With,
 }
 
 impl std::fmt::Display for FloatSanitizerKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Clamp => write!(f, "clamp"),
+            Self::Round => write!(f, "round"),
+            Self::Floor => write!(f, "floor"),
+            Self::Ceil => write!(f, "ceil"),
+            Self::Trunc => write!(f, "trunc"),
+            Self::Abs => write!(f, "abs"),
+            Self::ReplaceNan => write!(f, "replace_nan"),
             Self::With => write!(f, "with"),
         }
     }
@@ -34,10 +58,14 @@ impl<T> Kind for FloatSanitizer<T> {
 
     fn kind(&self) -> FloatSanitizerKind {
         match self {
+            Self::Clamp { .. } => FloatSanitizerKind::Clamp,
+            Self::Round(_) => FloatSanitizerKind::Round,
+            Self::Floor => FloatSanitizerKind::Floor,
+            Self::Ceil => FloatSanitizerKind::Ceil,
+            Self::Trunc => FloatSanitizerKind::Trunc,
+            Self::Abs => FloatSanitizerKind::Abs,
+            Self::ReplaceNan(_) => FloatSanitizerKind::ReplaceNan,
             Self::With(_) => FloatSanitizerKind::With,
-            Self::_Phantom(_) => {
-                unreachable!("Kind::kind(): FloatSanitizer::_Phantom must not be used")
-            }
         }
     }
 }
@@ -49,16 +77,33 @@ impl<T> Kind for FloatSanitizer<T> {
 pub enum FloatValidator<T> {
     Min(T),
     Max(T),
+    Greater(T),
+    Less(T),
+    /// The value must be a multiple of `step`. Without `epsilon` a tolerance of a few
+    /// ULPs is used to absorb rounding errors, e.g. `0.15` is a multiple of `0.05`.
+    MultipleOf {
+        step: T,
+        epsilon: Option<T>,
+    },
+    Finite,
+    NotNan,
     With(TokenStream),
+    NamedWith(NamedCustomValidator),
 }
 
 pub type SpannedFloatValidator<T> = SpannedItem<FloatValidator<T>>;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum FloatValidatorKind {
     Min,
     Max,
+    Greater,
+    Less,
+    MultipleOf,
+    Finite,
+    NotNan,
     With,
+    NamedWith(String),
 }
 
 impl std::fmt::Display for FloatValidatorKind {
@@ -66,7 +111,13 @@ impl std::fmt::Display for FloatValidatorKind {
         match self {
             Self::Min => write!(f, "min"),
             Self::Max => write!(f, "max"),
+            Self::Greater => write!(f, "greater"),
+            Self::Less => write!(f, "less"),
+            Self::MultipleOf => write!(f, "multiple_of"),
+            Self::Finite => write!(f, "finite"),
+            Self::NotNan => write!(f, "not_nan"),
             Self::With => write!(f, "with"),
+            Self::NamedWith(name) => write!(f, "{name}"),
         }
     }
 }
@@ -78,7 +129,13 @@ impl<T> Kind for FloatValidator<T> {
         match self {
             Self::Min(_) => FloatValidatorKind::Min,
             Self::Max(_) => FloatValidatorKind::Max,
+            Self::Greater(_) => FloatValidatorKind::Greater,
+            Self::Less(_) => FloatValidatorKind::Less,
+            Self::MultipleOf { .. } => FloatValidatorKind::MultipleOf,
+            Self::Finite => FloatValidatorKind::Finite,
+            Self::NotNan => FloatValidatorKind::NotNan,
             Self::With(_) => FloatValidatorKind::With,
+            Self::NamedWith(validator) => FloatValidatorKind::NamedWith(validator.name.to_string()),
         }
     }
 }
@@ -92,20 +149,46 @@ pub enum FloatDeriveTrait {
     Clone,
     Copy,
     PartialEq,
+    Eq,
     PartialOrd,
+    Ord,
     FromStr,
     AsRef,
+    Deref,
     Into,
     From,
     TryFrom,
+    Hash,
     Borrow,
     Display,
+    Default,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg,
+    Sum,
 
     // External crates
     SerdeSerialize,
     SerdeDeserialize,
+    SchemarsJsonSchema,
+    SqlxType,
     // Arbitrary,
 }
 
 pub type FloatRawGuard<T> = RawGuard<SpannedFloatSanitizer<T>, SpannedFloatValidator<T>>;
 pub type FloatGuard<T> = Guard<FloatSanitizer<T>, FloatValidator<T>>;
+
+impl<T> FloatGuard<T> {
+    /// Returns true if validation guarantees that the value is never NaN.
+    /// This is what makes it possible to implement `Eq`, `Ord` and `Hash` for a float type.
+    pub fn excludes_nan(&self) -> bool {
+        match self {
+            Self::WithoutValidation { .. } => false,
+            Self::WithValidation { validators, .. } => validators
+                .iter()
+                .any(|v| matches!(v, FloatValidator::Finite | FloatValidator::NotNan)),
+        }
+    }
+}
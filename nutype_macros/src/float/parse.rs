@@ -1,29 +1,42 @@
 use std::fmt::Debug;
 use std::str::FromStr;
 
+use crate::common::models::{Attributes, FloatType};
 use crate::common::parse::{
-    is_comma, parse_nutype_attributes, parse_value_as_number, parse_with_token_stream,
-    split_and_parse,
+    is_comma, parse_clamp_range, parse_multiple_of, parse_named_custom_validator,
+    parse_nutype_attributes, parse_parenthesized_number, parse_value_as_number,
+    parse_with_token_stream, split_and_parse,
 };
-use proc_macro2::{Span, TokenStream, TokenTree};
+use crate::common::validate::validate_error_options;
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
 
 use super::{
     models::{
         FloatGuard, FloatRawGuard, FloatSanitizer, FloatValidator, SpannedFloatSanitizer,
         SpannedFloatValidator,
     },
-    validate::validate_number_meta,
+    validate::{validate_float_default_value, validate_number_meta},
 };
 
-pub fn parse_attributes<T>(input: TokenStream) -> Result<FloatGuard<T>, syn::Error>
+pub fn parse_attributes<T>(
+    input: TokenStream,
+    float_type: FloatType,
+) -> Result<Attributes<FloatGuard<T>>, syn::Error>
 where
-    T: FromStr + PartialOrd + Clone,
+    T: FromStr + PartialOrd + Clone + Default + Into<f64>,
     <T as FromStr>::Err: Debug,
 {
-    parse_raw_attributes(input).and_then(validate_number_meta)
+    let raw_attributes = parse_raw_attributes(input)?;
+    validate_error_options(&raw_attributes, |v| {
+        matches!(v.item, FloatValidator::With(_))
+    })?;
+    let attributes =
+        raw_attributes.try_map_guard(|guard| validate_number_meta(guard, float_type))?;
+    validate_float_default_value(&attributes, float_type)?;
+    Ok(attributes)
 }
 
-fn parse_raw_attributes<T>(input: TokenStream) -> Result<FloatRawGuard<T>, syn::Error>
+fn parse_raw_attributes<T>(input: TokenStream) -> Result<Attributes<FloatRawGuard<T>>, syn::Error>
 where
     T: FromStr,
     <T as FromStr>::Err: Debug,
@@ -49,6 +62,49 @@ where
     let token = token_iter.next();
     if let Some(TokenTree::Ident(ident)) = token {
         match ident.to_string().as_ref() {
+            "clamp" => {
+                let (min, max) = parse_clamp_range(ident, token_iter.cloned())?;
+                Ok(SpannedFloatSanitizer {
+                    span: ident.span(),
+                    item: FloatSanitizer::Clamp { min, max },
+                })
+            }
+            "round" => {
+                let rest: Vec<TokenTree> = token_iter.cloned().collect();
+                let decimals = if rest.is_empty() {
+                    0
+                } else {
+                    parse_parenthesized_number(ident, rest.into_iter(), "round(2)")?
+                };
+                Ok(SpannedFloatSanitizer {
+                    span: ident.span(),
+                    item: FloatSanitizer::Round(decimals),
+                })
+            }
+            "floor" => Ok(SpannedFloatSanitizer {
+                span: ident.span(),
+                item: FloatSanitizer::Floor,
+            }),
+            "ceil" => Ok(SpannedFloatSanitizer {
+                span: ident.span(),
+                item: FloatSanitizer::Ceil,
+            }),
+            "trunc" => Ok(SpannedFloatSanitizer {
+                span: ident.span(),
+                item: FloatSanitizer::Trunc,
+            }),
+            "abs" => Ok(SpannedFloatSanitizer {
+                span: ident.span(),
+                item: FloatSanitizer::Abs,
+            }),
+            "replace_nan" => {
+                let replacement =
+                    parse_parenthesized_number(ident, token_iter.cloned(), "replace_nan(0.0)")?;
+                Ok(SpannedFloatSanitizer {
+                    span: ident.span(),
+                    item: FloatSanitizer::ReplaceNan(replacement),
+                })
+            }
             "with" => {
                 // Preserve the rest as `custom_sanitizer_fn`
                 let stream = parse_with_token_stream(token_iter, ident.span())?;
@@ -106,11 +162,62 @@ where
                 };
                 Ok(parsed_validator)
             }
+            "greater" => {
+                let (value, _iter) = parse_value_as_number(token_iter)?;
+                let validator = FloatValidator::Greater(value);
+                let parsed_validator = SpannedFloatValidator {
+                    span: ident.span(),
+                    item: validator,
+                };
+                Ok(parsed_validator)
+            }
+            "less" => {
+                let (value, _iter) = parse_value_as_number(token_iter)?;
+                let validator = FloatValidator::Less(value);
+                let parsed_validator = SpannedFloatValidator {
+                    span: ident.span(),
+                    item: validator,
+                };
+                Ok(parsed_validator)
+            }
+            "finite" => {
+                let validator = FloatValidator::Finite;
+                let parsed_validator = SpannedFloatValidator {
+                    span: ident.span(),
+                    item: validator,
+                };
+                Ok(parsed_validator)
+            }
+            "not_nan" => {
+                let validator = FloatValidator::NotNan;
+                let parsed_validator = SpannedFloatValidator {
+                    span: ident.span(),
+                    item: validator,
+                };
+                Ok(parsed_validator)
+            }
+            "multiple_of" => {
+                let (step, epsilon) = parse_multiple_of(&ident, token_iter, true)?;
+                let validator = FloatValidator::MultipleOf { step, epsilon };
+                let parsed_validator = SpannedFloatValidator {
+                    span: ident.span(),
+                    item: validator,
+                };
+                Ok(parsed_validator)
+            }
             "with" => {
                 let rest_tokens: Vec<_> = token_iter.collect();
-                let stream = parse_with_token_stream(rest_tokens.iter(), ident.span())?;
+                let validator = match rest_tokens.as_slice() {
+                    [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+                        let named = parse_named_custom_validator(group, ident.span())?;
+                        FloatValidator::NamedWith(named)
+                    }
+                    _ => {
+                        let stream = parse_with_token_stream(rest_tokens.iter(), ident.span())?;
+                        FloatValidator::With(stream)
+                    }
+                };
                 let span = ident.span();
-                let validator = FloatValidator::With(stream);
                 Ok(SpannedFloatValidator {
                     span,
                     item: validator,
@@ -1,26 +1,39 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, str::FromStr};
 
 use proc_macro2::Span;
+use quote::ToTokens;
 
 use crate::{
-    common::models::{DeriveTrait, NormalDeriveTrait, SpannedDeriveTrait},
-    common::validate::validate_duplicates,
+    common::models::{
+        Attributes, DeriveTrait, FloatType, Kind, NamedCustomValidator, NormalDeriveTrait,
+        SpannedDeriveTrait,
+    },
+    common::parse::parse_literal_number,
+    common::validate::{
+        gen_default_value_error, validate_duplicates, validate_error_variant_names,
+    },
 };
 
+use super::gen::error::error_variant_name;
 use super::models::{
     FloatDeriveTrait, FloatGuard, FloatRawGuard, FloatSanitizer, FloatValidator,
-    SpannedFloatSanitizer, SpannedFloatValidator,
+    FloatValidatorKind, SpannedFloatSanitizer, SpannedFloatValidator,
 };
 
-pub fn validate_number_meta<T>(raw_meta: FloatRawGuard<T>) -> Result<FloatGuard<T>, syn::Error>
+pub fn validate_number_meta<T>(
+    raw_meta: FloatRawGuard<T>,
+    float_type: FloatType,
+) -> Result<FloatGuard<T>, syn::Error>
 where
-    T: PartialOrd + Clone,
+    T: PartialOrd + Clone + Default,
 {
     let FloatRawGuard {
         sanitizers,
         validators,
     } = raw_meta;
 
+    validate_clamp(&sanitizers, &validators)?;
+    validate_round(&sanitizers, float_type)?;
     let validators = validate_validators(validators)?;
     let sanitizers = validate_sanitizers(sanitizers)?;
 
@@ -34,16 +47,78 @@ where
     }
 }
 
+/// Verifies at compile time that a literal default value (e.g. `default = 18`)
+/// satisfies the validators. Sanitizers may change the value, so if there are any,
+/// the check is left to `default()`, that panics on an invalid value.
+pub fn validate_float_default_value<T>(
+    attributes: &Attributes<FloatGuard<T>>,
+    float_type: FloatType,
+) -> Result<(), syn::Error>
+where
+    T: FromStr + PartialOrd + Clone + Into<f64>,
+{
+    let (default_value, validators) = match (&attributes.default, &attributes.guard) {
+        (
+            Some(default_value),
+            FloatGuard::WithValidation {
+                sanitizers,
+                validators,
+            },
+        ) if sanitizers.is_empty() => (default_value, validators),
+        _ => return Ok(()),
+    };
+    let value: T = match parse_literal_number(default_value) {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    for validator in validators {
+        let is_violated = match validator {
+            FloatValidator::Min(min) => value < *min,
+            FloatValidator::Max(max) => value > *max,
+            FloatValidator::Greater(greater) => value <= *greater,
+            FloatValidator::Less(less) => value >= *less,
+            FloatValidator::MultipleOf { step, epsilon } => is_not_multiple_of(
+                value.clone().into(),
+                step.clone().into(),
+                epsilon.clone().map(Into::into),
+                float_type,
+            ),
+            _ => false,
+        };
+        if is_violated {
+            return Err(gen_default_value_error(default_value, validator));
+        }
+    }
+    Ok(())
+}
+
+/// Mirrors the generated `multiple_of` check, but in `f64`.
+/// The default tolerance is based on the epsilon of the inner type, as it is at runtime.
+fn is_not_multiple_of(
+    value: f64,
+    step: f64,
+    maybe_epsilon: Option<f64>,
+    float_type: FloatType,
+) -> bool {
+    let tolerance = maybe_epsilon.unwrap_or(4.0 * float_type.epsilon() * value.abs().max(step));
+    !value.is_finite() || (value - (value / step).round() * step).abs() > tolerance
+}
+
 fn validate_validators<T>(
     validators: Vec<SpannedFloatValidator<T>>,
 ) -> Result<Vec<FloatValidator<T>>, syn::Error>
 where
-    T: PartialOrd + Clone,
+    T: PartialOrd + Clone + Default,
 {
     validate_duplicates(&validators, |kind| {
         format!("Duplicated validator `{kind}`.\nYou're a great engineer, but don't forget to take care of yourself!")
     })?;
 
+    validate_error_variant_names(&validators, error_variant_name, |v| match v {
+        FloatValidator::NamedWith(NamedCustomValidator { name, .. }) => Some(name),
+        _ => None,
+    })?;
+
     // max VS min
     let maybe_min = validators
         .iter()
@@ -67,10 +142,133 @@ where
         }
     }
 
+    // min VS greater, max VS less
+    for (inclusive, exclusive) in [
+        (FloatValidatorKind::Min, FloatValidatorKind::Greater),
+        (FloatValidatorKind::Max, FloatValidatorKind::Less),
+    ] {
+        let has_inclusive = validators.iter().any(|v| v.kind() == inclusive);
+        let maybe_exclusive = validators.iter().find(|v| v.kind() == exclusive);
+        if let (true, Some(exclusive_validator)) = (has_inclusive, maybe_exclusive) {
+            let msg = format!(
+                "`{inclusive}` and `{exclusive}` cannot be used together.\nOne bound on each side is enough!"
+            );
+            let err = syn::Error::new(exclusive_validator.span, msg);
+            return Err(err);
+        }
+    }
+
+    // lower VS upper bound, when at least one of them is exclusive
+    let maybe_lower = validators.iter().find_map(|v| match &v.item {
+        FloatValidator::Min(min) => Some((v.kind(), min.clone())),
+        FloatValidator::Greater(greater) => Some((v.kind(), greater.clone())),
+        _ => None,
+    });
+    let maybe_upper = validators.iter().find_map(|v| match &v.item {
+        FloatValidator::Max(max) => Some((v.span, v.kind(), max.clone())),
+        FloatValidator::Less(less) => Some((v.span, v.kind(), less.clone())),
+        _ => None,
+    });
+    if let (Some((lower_kind, lower)), Some((upper_span, upper_kind, upper))) =
+        (maybe_lower, maybe_upper)
+    {
+        let is_exclusive =
+            lower_kind == FloatValidatorKind::Greater || upper_kind == FloatValidatorKind::Less;
+        if is_exclusive && lower >= upper {
+            let msg = format!(
+                "`{lower_kind}` must be less than `{upper_kind}`, otherwise no value can pass the validation.\nSometimes we all need a little break."
+            );
+            let err = syn::Error::new(upper_span, msg);
+            return Err(err);
+        }
+    }
+
+    // finite VS not_nan
+    let has_finite = validators
+        .iter()
+        .any(|v| v.kind() == FloatValidatorKind::Finite);
+    let maybe_not_nan = validators
+        .iter()
+        .find(|v| v.kind() == FloatValidatorKind::NotNan);
+    if let (true, Some(not_nan)) = (has_finite, maybe_not_nan) {
+        let msg = "`not_nan` is redundant, because `finite` already rejects NaN.\nLess is more!";
+        let err = syn::Error::new(not_nan.span, msg);
+        return Err(err);
+    }
+
+    // multiple_of
+    for validator in &validators {
+        if let FloatValidator::MultipleOf { step, epsilon } = &validator.item {
+            if *step <= T::default() {
+                let msg = "`multiple_of` must be greater than zero.";
+                return Err(syn::Error::new(validator.span, msg));
+            }
+            if matches!(epsilon, Some(epsilon) if *epsilon < T::default()) {
+                let msg = "`epsilon` of `multiple_of` cannot be negative.";
+                return Err(syn::Error::new(validator.span, msg));
+            }
+        }
+    }
+
     let validators: Vec<_> = validators.into_iter().map(|v| v.item).collect();
     Ok(validators)
 }
 
+/// Ensures that `clamp` produces values, that pass `min` and `max` validators.
+fn validate_clamp<T>(
+    sanitizers: &[SpannedFloatSanitizer<T>],
+    validators: &[SpannedFloatValidator<T>],
+) -> Result<(), syn::Error>
+where
+    T: PartialOrd,
+{
+    for sanitizer in sanitizers {
+        if let FloatSanitizer::Clamp { min, max } = &sanitizer.item {
+            if min > max {
+                let msg = "The lower bound of `clamp` cannot be greater than the upper bound.\nIt's upside down!";
+                return Err(syn::Error::new(sanitizer.span, msg));
+            }
+            for validator in validators {
+                let msg = match &validator.item {
+                    FloatValidator::Min(min_bound) if min < min_bound => {
+                        "The lower bound of `clamp` is less than `min`, so clamped values can still be rejected by the validation."
+                    }
+                    FloatValidator::Max(max_bound) if max > max_bound => {
+                        "The upper bound of `clamp` is greater than `max`, so clamped values can still be rejected by the validation."
+                    }
+                    FloatValidator::Greater(greater) if min <= greater => {
+                        "The lower bound of `clamp` is not greater than `greater`, so clamped values can still be rejected by the validation."
+                    }
+                    FloatValidator::Less(less) if max >= less => {
+                        "The upper bound of `clamp` is not less than `less`, so clamped values can still be rejected by the validation."
+                    }
+                    _ => continue,
+                };
+                return Err(syn::Error::new(sanitizer.span, msg));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// `round(n)` multiplies the value by `10^n`, which must be finite.
+fn validate_round<T>(
+    sanitizers: &[SpannedFloatSanitizer<T>],
+    float_type: FloatType,
+) -> Result<(), syn::Error> {
+    let max_decimals = float_type.max_round_decimals();
+    for sanitizer in sanitizers {
+        if let FloatSanitizer::Round(decimals) = sanitizer.item {
+            if decimals > max_decimals {
+                let float_type = float_type.to_token_stream();
+                let msg = format!("`round` supports at most {max_decimals} decimal places for `{float_type}`, got {decimals}.");
+                return Err(syn::Error::new(sanitizer.span, msg));
+            }
+        }
+    }
+    Ok(())
+}
+
 fn validate_sanitizers<T>(
     sanitizers: Vec<SpannedFloatSanitizer<T>>,
 ) -> Result<Vec<FloatSanitizer<T>>, syn::Error>
@@ -85,20 +283,26 @@ where
     Ok(sanitizers)
 }
 
-pub fn validate_float_derive_traits(
+pub fn validate_float_derive_traits<T>(
+    guard: &FloatGuard<T>,
     spanned_derive_traits: Vec<SpannedDeriveTrait>,
-    has_validation: bool,
 ) -> Result<HashSet<FloatDeriveTrait>, syn::Error> {
     let mut traits = HashSet::with_capacity(24);
+    let has_validation = guard.has_validation();
+    let excludes_nan = guard.excludes_nan();
 
     for spanned_trait in spanned_derive_traits {
         match spanned_trait.item {
             DeriveTrait::Asterisk => {
-                traits.extend(unfold_asterisk_traits(has_validation));
+                traits.extend(unfold_asterisk_traits(has_validation, excludes_nan));
             }
             DeriveTrait::Normal(normal_trait) => {
-                let string_derive_trait =
-                    to_float_derive_trait(normal_trait, has_validation, spanned_trait.span)?;
+                let string_derive_trait = to_float_derive_trait(
+                    normal_trait,
+                    has_validation,
+                    excludes_nan,
+                    spanned_trait.span,
+                )?;
                 traits.insert(string_derive_trait);
             }
         };
@@ -107,14 +311,17 @@ pub fn validate_float_derive_traits(
     Ok(traits)
 }
 
-fn unfold_asterisk_traits(has_validation: bool) -> impl Iterator<Item = FloatDeriveTrait> {
+fn unfold_asterisk_traits(
+    has_validation: bool,
+    excludes_nan: bool,
+) -> impl Iterator<Item = FloatDeriveTrait> {
     let from_or_try_from = if has_validation {
         FloatDeriveTrait::TryFrom
     } else {
         FloatDeriveTrait::From
     };
 
-    [
+    let mut traits = vec![
         from_or_try_from,
         FloatDeriveTrait::Debug,
         FloatDeriveTrait::Clone,
@@ -123,36 +330,64 @@ fn unfold_asterisk_traits(has_validation: bool) -> impl Iterator<Item = FloatDer
         FloatDeriveTrait::PartialOrd,
         FloatDeriveTrait::FromStr,
         FloatDeriveTrait::AsRef,
-    ]
-    .into_iter()
+    ];
+
+    // Total order is possible only if NaN is excluded.
+    if excludes_nan {
+        traits.extend([
+            FloatDeriveTrait::Eq,
+            FloatDeriveTrait::Ord,
+            FloatDeriveTrait::Hash,
+        ]);
+    }
+
+    traits.into_iter()
 }
 
 fn to_float_derive_trait(
     tr: NormalDeriveTrait,
     has_validation: bool,
+    excludes_nan: bool,
     span: Span,
 ) -> Result<FloatDeriveTrait, syn::Error> {
     match tr {
         NormalDeriveTrait::Debug => Ok(FloatDeriveTrait::Debug),
         NormalDeriveTrait::Display => Ok(FloatDeriveTrait::Display),
+        NormalDeriveTrait::Default => Ok(FloatDeriveTrait::Default),
         NormalDeriveTrait::Clone => Ok(FloatDeriveTrait::Clone),
         NormalDeriveTrait::PartialEq => Ok(FloatDeriveTrait::PartialEq),
         NormalDeriveTrait::Into => Ok(FloatDeriveTrait::Into),
-        NormalDeriveTrait::Eq => Err(syn::Error::new(
-            span,
-            "#[nutype] cannot derive `Eq` trait for float types.",
-        )),
+        NormalDeriveTrait::Eq => {
+            if excludes_nan {
+                Ok(FloatDeriveTrait::Eq)
+            } else {
+                Err(syn::Error::new(span, "#[nutype] cannot derive `Eq` trait for float types, because NaN is not equal to itself.\nUse `finite` or `not_nan` validator to exclude NaN."))
+            }
+        }
         NormalDeriveTrait::PartialOrd => Ok(FloatDeriveTrait::PartialOrd),
-        NormalDeriveTrait::Ord => Err(syn::Error::new(
-            span,
-            "#[nutype] cannot derive `Ord` trait for float types.",
-        )),
+        NormalDeriveTrait::Ord => {
+            if excludes_nan {
+                Ok(FloatDeriveTrait::Ord)
+            } else {
+                Err(syn::Error::new(span, "#[nutype] cannot derive `Ord` trait for float types, because NaN cannot be compared.\nUse `finite` or `not_nan` validator to exclude NaN."))
+            }
+        }
         NormalDeriveTrait::FromStr => Ok(FloatDeriveTrait::FromStr),
         NormalDeriveTrait::AsRef => Ok(FloatDeriveTrait::AsRef),
-        NormalDeriveTrait::Hash => Err(syn::Error::new(
-            span,
-            "#[nutype] cannot derive `Hash` trait for float types.",
-        )),
+        NormalDeriveTrait::Deref => Ok(FloatDeriveTrait::Deref),
+        NormalDeriveTrait::Add => Ok(FloatDeriveTrait::Add),
+        NormalDeriveTrait::Sub => Ok(FloatDeriveTrait::Sub),
+        NormalDeriveTrait::Mul => Ok(FloatDeriveTrait::Mul),
+        NormalDeriveTrait::Div => Ok(FloatDeriveTrait::Div),
+        NormalDeriveTrait::Neg => Ok(FloatDeriveTrait::Neg),
+        NormalDeriveTrait::Sum => Ok(FloatDeriveTrait::Sum),
+        NormalDeriveTrait::Hash => {
+            if excludes_nan {
+                Ok(FloatDeriveTrait::Hash)
+            } else {
+                Err(syn::Error::new(span, "#[nutype] cannot derive `Hash` trait for float types, because NaN is not equal to itself.\nUse `finite` or `not_nan` validator to exclude NaN."))
+            }
+        }
         NormalDeriveTrait::Borrow => Ok(FloatDeriveTrait::Borrow),
         NormalDeriveTrait::Copy => Ok(FloatDeriveTrait::Copy),
         NormalDeriveTrait::From => {
@@ -171,5 +406,7 @@ fn to_float_derive_trait(
         }
         NormalDeriveTrait::SerdeSerialize => Ok(FloatDeriveTrait::SerdeSerialize),
         NormalDeriveTrait::SerdeDeserialize => Ok(FloatDeriveTrait::SerdeDeserialize),
+        NormalDeriveTrait::SchemarsJsonSchema => Ok(FloatDeriveTrait::SchemarsJsonSchema),
+        NormalDeriveTrait::SqlxType => Ok(FloatDeriveTrait::SqlxType),
     }
 }
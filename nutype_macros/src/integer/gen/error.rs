@@ -1,19 +1,25 @@
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 
 use super::super::models::IntegerValidator;
 use crate::common::{
-    gen::error::{gen_error_type_name, gen_impl_error_trait},
-    models::TypeName,
+    gen::error::{
+        gen_bound_error_variant, gen_error_type_name, gen_impl_error_trait,
+        gen_named_validator_message,
+    },
+    models::{ErrorOptions, IntegerType, NamedCustomValidator, TypeName},
 };
 
 pub fn gen_validation_error_type<T>(
     type_name: &TypeName,
+    inner_type: IntegerType,
     validators: &[IntegerValidator<T>],
+    error_options: &ErrorOptions,
 ) -> TokenStream {
+    let detailed_errors = error_options.detailed;
     let error_type_name = gen_error_type_name(type_name);
-    let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(&error_type_name, validators);
+    let definition = gen_definition(&error_type_name, inner_type, validators, detailed_errors);
+    let impl_display_trait = gen_impl_display_trait(&error_type_name, validators, error_options);
     let impl_error_trait = gen_impl_error_trait(&error_type_name);
 
     quote! {
@@ -25,19 +31,36 @@ pub fn gen_validation_error_type<T>(
     }
 }
 
-fn gen_definition<T>(error_type_name: &Ident, validators: &[IntegerValidator<T>]) -> TokenStream {
+fn gen_definition<T>(
+    error_type_name: &Ident,
+    inner_type: IntegerType,
+    validators: &[IntegerValidator<T>],
+    detailed_errors: bool,
+) -> TokenStream {
     let error_variants: TokenStream = validators
         .iter()
         .map(|validator| match validator {
             IntegerValidator::Min(_) => {
-                quote!(TooSmall,)
+                gen_bound_error_variant("TooSmall", "min", inner_type, detailed_errors)
             }
             IntegerValidator::Max(_) => {
-                quote!(TooBig,)
+                gen_bound_error_variant("TooBig", "max", inner_type, detailed_errors)
+            }
+            IntegerValidator::Greater(_) => {
+                gen_bound_error_variant("TooSmall", "greater", inner_type, detailed_errors)
+            }
+            IntegerValidator::Less(_) => {
+                gen_bound_error_variant("TooBig", "less", inner_type, detailed_errors)
+            }
+            IntegerValidator::MultipleOf(_) => {
+                gen_bound_error_variant("NotMultipleOf", "multiple_of", inner_type, detailed_errors)
             }
             IntegerValidator::With(_) => {
                 quote!(Invalid,)
             }
+            IntegerValidator::NamedWith(NamedCustomValidator { name, .. }) => {
+                quote!(#name,)
+            }
         })
         .collect();
 
@@ -51,17 +74,52 @@ fn gen_definition<T>(error_type_name: &Ident, validators: &[IntegerValidator<T>]
 fn gen_impl_display_trait<T>(
     error_type_name: &Ident,
     validators: &[IntegerValidator<T>],
+    error_options: &ErrorOptions,
 ) -> TokenStream {
-    let match_arms = validators.iter().map(|validator| match validator {
-        IntegerValidator::Min(_) => quote! {
+    let detailed_errors = error_options.detailed;
+    let match_arms = validators.iter().map(|validator| {
+        if let Some(message) = error_options.message_for(validator) {
+            let variant = error_variant_name(validator);
+            return quote! {
+                #error_type_name::#variant { .. } => f.write_str(#message)
+            };
+        }
+
+        match validator {
+        IntegerValidator::Min(_) if detailed_errors => quote! {
+             #error_type_name::TooSmall { min, actual } => write!(f, "too small: expected at least {min}, got {actual}")
+        },
+        IntegerValidator::Max(_) if detailed_errors => quote! {
+             #error_type_name::TooBig { max, actual } => write!(f, "too big: expected at most {max}, got {actual}")
+        },
+        IntegerValidator::Greater(_) if detailed_errors => quote! {
+             #error_type_name::TooSmall { greater, actual } => write!(f, "too small: expected greater than {greater}, got {actual}")
+        },
+        IntegerValidator::Less(_) if detailed_errors => quote! {
+             #error_type_name::TooBig { less, actual } => write!(f, "too big: expected less than {less}, got {actual}")
+        },
+        IntegerValidator::MultipleOf(_) if detailed_errors => quote! {
+             #error_type_name::NotMultipleOf { multiple_of, actual } => write!(f, "not a multiple: expected a multiple of {multiple_of}, got {actual}")
+        },
+        IntegerValidator::Min(_) | IntegerValidator::Greater(_) => quote! {
              #error_type_name::TooSmall => write!(f, "too small")
         },
-        IntegerValidator::Max(_) => quote! {
+        IntegerValidator::Max(_) | IntegerValidator::Less(_) => quote! {
              #error_type_name::TooBig=> write!(f, "too big")
         },
+        IntegerValidator::MultipleOf(_) => quote! {
+             #error_type_name::NotMultipleOf => write!(f, "not a multiple")
+        },
         IntegerValidator::With(_) => quote! {
              #error_type_name::Invalid => write!(f, "invalid")
         },
+        IntegerValidator::NamedWith(NamedCustomValidator { name, .. }) => {
+            let message = gen_named_validator_message(name);
+            quote! {
+                 #error_type_name::#name => f.write_str(#message)
+            }
+        }
+    }
     });
 
     quote! {
@@ -74,3 +132,14 @@ fn gen_impl_display_trait<T>(
         }
     }
 }
+
+pub fn error_variant_name<T>(validator: &IntegerValidator<T>) -> Ident {
+    let name = match validator {
+        IntegerValidator::Min(_) | IntegerValidator::Greater(_) => "TooSmall",
+        IntegerValidator::Max(_) | IntegerValidator::Less(_) => "TooBig",
+        IntegerValidator::MultipleOf(_) => "NotMultipleOf",
+        IntegerValidator::With(_) => "Invalid",
+        IntegerValidator::NamedWith(NamedCustomValidator { name, .. }) => return name.clone(),
+    };
+    Ident::new(name, Span::call_site())
+}
@@ -5,16 +5,25 @@ use std::collections::HashSet;
 
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
-use syn::Visibility;
+use syn::{Generics, Visibility};
 
-use self::{error::gen_validation_error_type, traits::gen_traits};
+use self::{
+    error::gen_validation_error_type,
+    traits::{gen_json_schema_constraints, gen_traits},
+};
 use super::models::{IntegerDeriveTrait, IntegerGuard, IntegerSanitizer, IntegerValidator};
 use crate::{
     common::gen::{
-        error::gen_error_type_name, gen_module_name_for_type, gen_reimports,
-        parse_error::gen_parse_error_name, traits::GeneratedTraits, type_custom_closure,
+        error::{gen_bound_error, gen_error_type_name, gen_error_type_path},
+        gen_const_check, gen_constraint_rule, gen_custom_validator_check, gen_impl_constraints,
+        gen_impl_nutype_trait, gen_module_name_for_type, gen_named_custom_validator_check,
+        gen_new_const, gen_new_unchecked, gen_reimports, gen_report_error,
+        gen_sanitize_and_validate_fns, gen_validate_fn_from_checks,
+        parse_error::gen_parse_error_name,
+        traits::{gen_json_schema_refinements, GeneratedTraits, SaturationBounds},
+        type_custom_closure, ConstraintValue,
     },
-    common::models::{IntegerType, TypeName},
+    common::models::{Attributes, ErrorOptions, IntegerType, Kind, NamedCustomValidator, TypeName},
 };
 
 pub fn gen_nutype_for_integer<T>(
@@ -22,19 +31,40 @@ pub fn gen_nutype_for_integer<T>(
     vis: Visibility,
     number_type: IntegerType,
     type_name: &TypeName,
-    meta: IntegerGuard<T>,
+    attributes: Attributes<IntegerGuard<T>>,
     traits: HashSet<IntegerDeriveTrait>,
 ) -> TokenStream
 where
     T: ToTokens + PartialOrd,
 {
+    let Attributes {
+        guard: meta,
+        error_options,
+        default,
+        new_unchecked,
+    } = attributes;
     let module_name = gen_module_name_for_type(type_name);
-    let implementation = gen_implementation(type_name, number_type, &meta);
+    let implementation =
+        gen_implementation(type_name, number_type, &meta, &error_options, new_unchecked);
     let inner_type: TokenStream = quote!(#number_type);
 
-    let maybe_error_type_name: Option<Ident> = match meta {
+    let maybe_error_type_name: Option<syn::Path> = match meta {
         IntegerGuard::WithoutValidation { .. } => None,
-        IntegerGuard::WithValidation { .. } => Some(gen_error_type_name(type_name)),
+        IntegerGuard::WithValidation { .. } => Some(gen_error_type_path(type_name, &error_options)),
+    };
+    let impl_nutype_trait = gen_impl_nutype_trait(
+        type_name,
+        number_type,
+        &Generics::default(),
+        maybe_error_type_name.as_ref(),
+    );
+
+    // A custom error type is defined by the user, so only the generated one needs to be reimported.
+    let maybe_reimported_error_type_name: Option<Ident> = match meta {
+        IntegerGuard::WithValidation { .. } if error_options.custom_type.is_none() => {
+            Some(gen_error_type_name(type_name))
+        }
+        _ => None,
     };
 
     let maybe_parse_error_type_name = if traits.contains(&IntegerDeriveTrait::FromStr) {
@@ -47,14 +77,22 @@ where
         vis,
         type_name,
         &module_name,
-        maybe_error_type_name.as_ref(),
+        maybe_reimported_error_type_name.as_ref(),
         maybe_parse_error_type_name.as_ref(),
     );
 
     let GeneratedTraits {
         derive_standard_traits,
         implement_traits,
-    } = gen_traits(type_name, &inner_type, maybe_error_type_name, traits);
+    } = gen_traits(
+        type_name,
+        &inner_type,
+        maybe_error_type_name,
+        default,
+        gen_saturation_bounds(&meta),
+        gen_json_schema_refinements(&doc_attrs, gen_json_schema_constraints(&meta)),
+        traits,
+    );
 
     quote!(
         #[doc(hidden)]
@@ -66,37 +104,178 @@ where
             pub struct #type_name(#inner_type);
 
             #implementation
+            #impl_nutype_trait
             #implement_traits
         }
         #reimports
     )
 }
 
+/// Values can be clamped into a valid range only if `min` and `max` are the only validators
+/// and there are no sanitizers, that could change the clamped value.
+fn gen_saturation_bounds<T>(meta: &IntegerGuard<T>) -> Option<SaturationBounds>
+where
+    T: ToTokens,
+{
+    match meta {
+        IntegerGuard::WithoutValidation { .. } => Some(SaturationBounds {
+            min: None,
+            max: None,
+            clamps_div: true,
+        }),
+        IntegerGuard::WithValidation {
+            sanitizers,
+            validators,
+        } => {
+            if !sanitizers.is_empty() {
+                return None;
+            }
+            let mut bounds = SaturationBounds {
+                min: None,
+                max: None,
+                clamps_div: true,
+            };
+            for validator in validators {
+                match validator {
+                    IntegerValidator::Min(min) => bounds.min = Some(quote!(#min)),
+                    IntegerValidator::Max(max) => bounds.max = Some(quote!(#max)),
+                    IntegerValidator::Greater(_)
+                    | IntegerValidator::Less(_)
+                    | IntegerValidator::MultipleOf(_)
+                    | IntegerValidator::With(_)
+                    | IntegerValidator::NamedWith(_) => return None,
+                }
+            }
+            Some(bounds)
+        }
+    }
+}
+
 pub fn gen_implementation<T>(
     type_name: &TypeName,
     inner_type: IntegerType,
     meta: &IntegerGuard<T>,
+    error_options: &ErrorOptions,
+    new_unchecked: bool,
 ) -> TokenStream
 where
     T: ToTokens + PartialOrd,
 {
     let convert_implementation = match meta {
         IntegerGuard::WithoutValidation { sanitizers } => {
-            gen_new_without_validation(type_name, inner_type, sanitizers)
+            gen_new_without_validation(type_name, inner_type, sanitizers, new_unchecked)
         }
         IntegerGuard::WithValidation {
             sanitizers,
             validators,
-        } => gen_new_with_validation(type_name, inner_type, sanitizers, validators),
+        } => gen_new_with_validation(
+            type_name,
+            inner_type,
+            sanitizers,
+            validators,
+            error_options,
+            new_unchecked,
+        ),
     };
+    let new_const = gen_new_const_if_possible(type_name, inner_type, meta);
+    let constraints = gen_constraints(type_name, inner_type, meta);
     let methods = gen_impl_methods(type_name, inner_type);
 
     quote! {
         #convert_implementation
+        #new_const
+        #constraints
         #methods
     }
 }
 
+fn gen_constraints<T>(
+    type_name: &TypeName,
+    inner_type: IntegerType,
+    meta: &IntegerGuard<T>,
+) -> TokenStream
+where
+    T: ToTokens,
+{
+    let value = if inner_type.is_signed() {
+        ConstraintValue::Int
+    } else {
+        ConstraintValue::UInt
+    };
+    let sanitizers = meta
+        .sanitizers()
+        .iter()
+        .map(|sanitizer| match sanitizer {
+            IntegerSanitizer::Clamp { min, max } => {
+                gen_constraint_rule(sanitizer.kind(), &[value.gen(min), value.gen(max)])
+            }
+            IntegerSanitizer::With(_) => gen_constraint_rule(sanitizer.kind(), &[]),
+        })
+        .collect();
+    let validators = meta
+        .validators()
+        .iter()
+        .map(|validator| match validator {
+            IntegerValidator::Min(bound)
+            | IntegerValidator::Max(bound)
+            | IntegerValidator::Greater(bound)
+            | IntegerValidator::Less(bound)
+            | IntegerValidator::MultipleOf(bound) => {
+                gen_constraint_rule(validator.kind(), &[value.gen(bound)])
+            }
+            IntegerValidator::With(_) => gen_constraint_rule(validator.kind(), &[]),
+            IntegerValidator::NamedWith(NamedCustomValidator { name, .. }) => {
+                gen_constraint_rule("with", &[ConstraintValue::Str.gen(name.to_string())])
+            }
+        })
+        .collect();
+    gen_impl_constraints(
+        type_name,
+        inner_type,
+        &Generics::default(),
+        sanitizers,
+        validators,
+    )
+}
+
+/// `new_const()` is generated only if a value can be checked in a const context:
+/// there are no sanitizers and all the validators are built-in.
+fn gen_new_const_if_possible<T>(
+    type_name: &TypeName,
+    inner_type: IntegerType,
+    meta: &IntegerGuard<T>,
+) -> TokenStream
+where
+    T: ToTokens,
+{
+    let validators: &[IntegerValidator<T>] = match meta {
+        IntegerGuard::WithoutValidation { sanitizers } if sanitizers.is_empty() => &[],
+        IntegerGuard::WithValidation {
+            sanitizers,
+            validators,
+        } if sanitizers.is_empty() => validators,
+        _ => return quote!(),
+    };
+    let maybe_checks: Option<TokenStream> = validators
+        .iter()
+        .map(|validator| {
+            let violation = match validator {
+                IntegerValidator::Min(min) => quote!(val < #min),
+                IntegerValidator::Max(max) => quote!(val > #max),
+                IntegerValidator::Greater(greater) => quote!(val <= #greater),
+                IntegerValidator::Less(less) => quote!(val >= #less),
+                IntegerValidator::MultipleOf(multiple_of) => quote!(val % #multiple_of != 0),
+                IntegerValidator::With(_) | IntegerValidator::NamedWith(_) => return None,
+            };
+            Some(gen_const_check(type_name, violation, validator.kind()))
+        })
+        .collect();
+    match maybe_checks {
+        Some(checks) => gen_new_const(type_name, inner_type, checks),
+        None => quote!(),
+    }
+}
+
 fn gen_impl_methods(type_name: &TypeName, inner_type: IntegerType) -> TokenStream {
     quote! {
         impl #type_name {
@@ -111,18 +290,41 @@ fn gen_new_without_validation<T>(
     type_name: &TypeName,
     inner_type: IntegerType,
     sanitizers: &[IntegerSanitizer<T>],
+    new_unchecked: bool,
 ) -> TokenStream
 where
     T: ToTokens + PartialOrd,
 {
     let sanitize = gen_sanitize_fn(inner_type, sanitizers);
+    let new_unchecked = if new_unchecked {
+        gen_new_unchecked(
+            type_name,
+            quote!(raw_value: #inner_type),
+            quote!(raw_value),
+            false,
+        )
+    } else {
+        quote!()
+    };
+
+    let sanitize_and_validate = gen_sanitize_and_validate_fns(
+        quote!(#inner_type),
+        quote!(raw_value: #inner_type),
+        quote!(raw_value),
+        quote!(&#inner_type),
+        sanitize,
+        None,
+    );
 
     quote!(
         impl #type_name {
             pub fn new(raw_value: #inner_type) -> Self {
-                #sanitize
-                Self(sanitize(raw_value))
+                Self(Self::sanitize(raw_value))
             }
+
+            #sanitize_and_validate
+
+            #new_unchecked
         }
     )
 }
@@ -132,29 +334,71 @@ fn gen_new_with_validation<T>(
     inner_type: IntegerType,
     sanitizers: &[IntegerSanitizer<T>],
     validators: &[IntegerValidator<T>],
+    error_options: &ErrorOptions,
+    new_unchecked: bool,
 ) -> TokenStream
 where
     T: ToTokens + PartialOrd,
 {
     let sanitize = gen_sanitize_fn(inner_type, sanitizers);
-    let validation_error = gen_validation_error_type(type_name, validators);
-    let error_type_name = gen_error_type_name(type_name);
-    let validate = gen_validate_fn(type_name, inner_type, validators);
+    let validation_error = if error_options.custom_type.is_none() {
+        gen_validation_error_type(type_name, inner_type, validators, error_options)
+    } else {
+        quote!()
+    };
+    let error_type_name = gen_error_type_path(type_name, error_options);
+    let validate = gen_validate_fn(type_name, inner_type, validators, error_options, false);
+
+    let new_collecting = if error_options.collect {
+        let validate_all = gen_validate_fn(type_name, inner_type, validators, error_options, true);
+        quote!(
+            /// Same as `new()`, but instead of stopping at the first error, returns all the violated validation rules.
+            pub fn new_collecting(raw_value: #inner_type) -> ::core::result::Result<Self, ::std::vec::Vec<#error_type_name>> {
+                #validate_all
+
+                let sanitized_value = Self::sanitize(raw_value);
+                validate_all(sanitized_value)?;
+                Ok(#type_name(sanitized_value))
+            }
+        )
+    } else {
+        quote!()
+    };
+
+    let new_unchecked = if new_unchecked {
+        gen_new_unchecked(
+            type_name,
+            quote!(raw_value: #inner_type),
+            quote!(raw_value),
+            true,
+        )
+    } else {
+        quote!()
+    };
+
+    let sanitize_and_validate = gen_sanitize_and_validate_fns(
+        quote!(#inner_type),
+        quote!(raw_value: #inner_type),
+        quote!(raw_value),
+        quote!(&#inner_type),
+        sanitize,
+        Some((&error_type_name, quote!(#validate validate(*value)))),
+    );
 
     quote!(
         #validation_error
 
         impl #type_name {
             pub fn new(raw_value: #inner_type) -> ::core::result::Result<Self, #error_type_name> {
-                // Keep sanitize() and validate() within new() so they do not overlap with outer
-                // scope imported with `use super::*`.
-                #sanitize
-                #validate
-
-                let sanitized_value = sanitize(raw_value);
-                validate(sanitized_value)?;
+                let sanitized_value = Self::sanitize(raw_value);
+                Self::validate_sanitized(&sanitized_value)?;
                 Ok(#type_name(sanitized_value))
             }
+
+            #sanitize_and_validate
+
+            #new_collecting
+            #new_unchecked
         }
     )
 }
@@ -166,15 +410,15 @@ where
     let transformations: TokenStream = sanitizers
         .iter()
         .map(|san| match san {
+            IntegerSanitizer::Clamp { min, max } => quote!(
+                value = value.clamp(#min, #max);
+            ),
             IntegerSanitizer::With(token_stream) => {
                 let custom_sanitizer = type_custom_closure(token_stream, inner_type);
                 quote!(
                     value = (#custom_sanitizer)(value);
                 )
             }
-            IntegerSanitizer::_Phantom(_) => {
-                unreachable!("integer::gen: IntegerSanitizer::_Phantom must not be used")
-            }
         })
         .collect();
 
@@ -190,45 +434,91 @@ fn gen_validate_fn<T>(
     type_name: &TypeName,
     inner_type: IntegerType,
     validators: &[IntegerValidator<T>],
+    error_options: &ErrorOptions,
+    collect: bool,
 ) -> TokenStream
 where
     T: ToTokens + PartialOrd,
 {
-    let error_name = gen_error_type_name(type_name);
+    let error_name = gen_error_type_path(type_name, error_options);
+    let detailed_errors = error_options.detailed;
+    let report_error = |error| gen_report_error(error, collect);
+    let bound_error = |variant, bound_name, bound: &T| {
+        gen_bound_error(
+            &error_name,
+            variant,
+            bound_name,
+            bound,
+            quote!(val),
+            detailed_errors,
+        )
+    };
 
     let validations: TokenStream = validators
         .iter()
         .map(|validator| match validator {
             IntegerValidator::Max(max) => {
+                let error = bound_error("TooBig", "max", max);
+                let report = report_error(error);
                 quote!(
                     if val > #max {
-                        return Err(#error_name::TooBig);
+                        #report
                     }
                 )
             }
             IntegerValidator::Min(min) => {
+                let error = bound_error("TooSmall", "min", min);
+                let report = report_error(error);
                 quote!(
                     if val < #min {
-                        return Err(#error_name::TooSmall);
+                        #report
                     }
                 )
             }
-            IntegerValidator::With(is_valid_fn) => {
-                let inner_type_ref = quote!(&#inner_type);
-                let is_valid_fn = type_custom_closure(is_valid_fn, inner_type_ref);
+            IntegerValidator::Greater(greater) => {
+                let error = bound_error("TooSmall", "greater", greater);
+                let report = report_error(error);
                 quote!(
-                    if !(#is_valid_fn)(&val) {
-                        return Err(#error_name::Invalid);
+                    if val <= #greater {
+                        #report
                     }
                 )
             }
+            IntegerValidator::Less(less) => {
+                let error = bound_error("TooBig", "less", less);
+                let report = report_error(error);
+                quote!(
+                    if val >= #less {
+                        #report
+                    }
+                )
+            }
+            IntegerValidator::MultipleOf(multiple_of) => {
+                let error = bound_error("NotMultipleOf", "multiple_of", multiple_of);
+                let report = report_error(error);
+                quote!(
+                    if val % #multiple_of != 0 {
+                        #report
+                    }
+                )
+            }
+            IntegerValidator::With(is_valid_fn) => {
+                let inner_type_ref = quote!(&#inner_type);
+                let is_valid_fn = type_custom_closure(is_valid_fn, inner_type_ref);
+                gen_custom_validator_check(is_valid_fn, &error_name, error_options, collect)
+            }
+            IntegerValidator::NamedWith(NamedCustomValidator { name, func }) => {
+                let is_valid_fn = type_custom_closure(func, quote!(&#inner_type));
+                gen_named_custom_validator_check(is_valid_fn, &error_name, name, collect)
+            }
         })
         .collect();
 
-    quote!(
-        fn validate(val: #inner_type) -> ::core::result::Result<(), #error_name> {
-            #validations
-            Ok(())
-        }
+    gen_validate_fn_from_checks(
+        quote!(val: #inner_type),
+        &Generics::default(),
+        &error_name,
+        validations,
+        collect,
     )
 }
@@ -1,20 +1,23 @@
 use std::collections::HashSet;
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use crate::{
     common::{
         gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_dislpay,
-            gen_impl_trait_from, gen_impl_trait_from_str, gen_impl_trait_into,
-            gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
-            gen_impl_trait_try_from, split_into_generatable_traits, GeneratableTrait,
-            GeneratableTraits, GeneratedTraits,
+            gen_arithmetic, gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
+            gen_impl_trait_deref, gen_impl_trait_dislpay, gen_impl_trait_from,
+            gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_neg,
+            gen_impl_trait_schemars_json_schema, gen_impl_trait_serde_deserialize,
+            gen_impl_trait_serde_serialize, gen_impl_trait_sqlx_type, gen_impl_trait_sum,
+            gen_impl_trait_try_from, split_into_generatable_traits, ArithmeticOperator,
+            GeneratableTrait, GeneratableTraits, GeneratedTraits, SaturationBounds,
         },
         models::TypeName,
     },
-    integer::models::IntegerDeriveTrait,
+    integer::models::{IntegerDeriveTrait, IntegerGuard, IntegerValidator},
 };
 
 type IntegerGeneratableTrait = GeneratableTrait<IntegerStandardTrait, IntegerIrregularTrait>;
@@ -22,7 +25,10 @@ type IntegerGeneratableTrait = GeneratableTrait<IntegerStandardTrait, IntegerIrr
 pub fn gen_traits(
     type_name: &TypeName,
     inner_type: &TokenStream,
-    maybe_error_type_name: Option<Ident>,
+    maybe_error_type_name: Option<syn::Path>,
+    maybe_default_value: Option<syn::Expr>,
+    maybe_saturation_bounds: Option<SaturationBounds>,
+    schema_refinements: TokenStream,
     traits: HashSet<IntegerDeriveTrait>,
 ) -> GeneratedTraits {
     let GeneratableTraits {
@@ -40,6 +46,9 @@ pub fn gen_traits(
         type_name,
         inner_type,
         maybe_error_type_name,
+        maybe_default_value,
+        maybe_saturation_bounds,
+        schema_refinements,
         irregular_traits,
     );
 
@@ -78,6 +87,9 @@ impl From<IntegerDeriveTrait> for IntegerGeneratableTrait {
             IntegerDeriveTrait::AsRef => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::AsRef)
             }
+            IntegerDeriveTrait::Deref => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Deref)
+            }
             IntegerDeriveTrait::Into => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Into)
             }
@@ -90,6 +102,27 @@ impl From<IntegerDeriveTrait> for IntegerGeneratableTrait {
             IntegerDeriveTrait::Borrow => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Borrow)
             }
+            IntegerDeriveTrait::Default => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Default)
+            }
+            IntegerDeriveTrait::Add => IntegerGeneratableTrait::Irregular(
+                IntegerIrregularTrait::Arithmetic(ArithmeticOperator::Add),
+            ),
+            IntegerDeriveTrait::Sub => IntegerGeneratableTrait::Irregular(
+                IntegerIrregularTrait::Arithmetic(ArithmeticOperator::Sub),
+            ),
+            IntegerDeriveTrait::Mul => IntegerGeneratableTrait::Irregular(
+                IntegerIrregularTrait::Arithmetic(ArithmeticOperator::Mul),
+            ),
+            IntegerDeriveTrait::Div => IntegerGeneratableTrait::Irregular(
+                IntegerIrregularTrait::Arithmetic(ArithmeticOperator::Div),
+            ),
+            IntegerDeriveTrait::Neg => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Neg)
+            }
+            IntegerDeriveTrait::Sum => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Sum)
+            }
             IntegerDeriveTrait::Display => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Display)
             }
@@ -99,6 +132,12 @@ impl From<IntegerDeriveTrait> for IntegerGeneratableTrait {
             IntegerDeriveTrait::SerdeDeserialize => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::SerdeDeserialize)
             }
+            IntegerDeriveTrait::SchemarsJsonSchema => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::SchemarsJsonSchema)
+            }
+            IntegerDeriveTrait::SqlxType => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::SqlxType)
+            }
         }
     }
 }
@@ -122,13 +161,20 @@ enum IntegerStandardTrait {
 enum IntegerIrregularTrait {
     FromStr,
     AsRef,
+    Deref,
     From,
     TryFrom,
     Borrow,
     Into,
     Display,
+    Default,
+    Arithmetic(ArithmeticOperator),
+    Neg,
+    Sum,
     SerdeSerialize,
     SerdeDeserialize,
+    SchemarsJsonSchema,
+    SqlxType,
 }
 
 impl ToTokens for IntegerStandardTrait {
@@ -150,32 +196,126 @@ impl ToTokens for IntegerStandardTrait {
 fn gen_implemented_traits(
     type_name: &TypeName,
     inner_type: &TokenStream,
-    maybe_error_type_name: Option<Ident>,
+    maybe_error_type_name: Option<syn::Path>,
+    maybe_default_value: Option<syn::Expr>,
+    maybe_saturation_bounds: Option<SaturationBounds>,
+    schema_refinements: TokenStream,
     impl_traits: Vec<IntegerIrregularTrait>,
 ) -> TokenStream {
+    let generics = Generics::default();
     impl_traits
         .iter()
         .map(|t| match t {
-            IntegerIrregularTrait::AsRef => gen_impl_trait_as_ref(type_name, inner_type),
+            IntegerIrregularTrait::AsRef => gen_impl_trait_as_ref(type_name, &generics, inner_type),
+            IntegerIrregularTrait::Deref => gen_impl_trait_deref(type_name, &generics, inner_type),
             IntegerIrregularTrait::FromStr => {
                 gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref())
             }
-            IntegerIrregularTrait::From => gen_impl_trait_from(type_name, inner_type),
-            IntegerIrregularTrait::Into => gen_impl_trait_into(type_name, inner_type),
+            IntegerIrregularTrait::From => gen_impl_trait_from(type_name, &generics, inner_type),
+            IntegerIrregularTrait::Into => gen_impl_trait_into(type_name, &generics, inner_type),
             IntegerIrregularTrait::TryFrom => {
                 let error_type_name = maybe_error_type_name
                     .as_ref()
                     .expect("TryFrom for integer is expected to have error_type_name");
-                gen_impl_trait_try_from(type_name, inner_type, error_type_name)
+                gen_impl_trait_try_from(type_name, &generics, inner_type, error_type_name)
+            }
+            IntegerIrregularTrait::Borrow => {
+                gen_impl_trait_borrow(type_name, &generics, inner_type)
+            }
+            IntegerIrregularTrait::Default => {
+                let default_value = maybe_default_value
+                    .as_ref()
+                    .expect("Default trait is expected to have default value");
+                gen_impl_trait_default(
+                    type_name,
+                    &generics,
+                    default_value,
+                    maybe_error_type_name.is_some(),
+                )
+            }
+            IntegerIrregularTrait::Display => gen_impl_trait_dislpay(type_name, &generics),
+            IntegerIrregularTrait::Arithmetic(operator) => {
+                let checked_method_name = operator.checked_method_name();
+                let saturating_method_name = operator.saturating_method_name();
+                // Overflow panics regardless of the build profile, so a wrapped value never
+                // makes it into the type. Division by zero and `MIN / -1` panic anyway.
+                let value = match operator {
+                    ArithmeticOperator::Div => operator.apply(quote!(self.0), quote!(rhs.0)),
+                    _ => {
+                        let message = format!("attempt to {} with overflow", operator.name());
+                        quote!(self.0.#checked_method_name(rhs.0).expect(#message))
+                    }
+                };
+                gen_arithmetic(
+                    type_name,
+                    *operator,
+                    maybe_error_type_name.as_ref(),
+                    maybe_saturation_bounds.as_ref(),
+                    value,
+                    quote!(self.0.#checked_method_name(rhs.0)?),
+                    quote!(self.0.#saturating_method_name(rhs.0)),
+                )
+            }
+            IntegerIrregularTrait::Neg => gen_impl_trait_neg(
+                type_name,
+                maybe_error_type_name.as_ref(),
+                quote!(self
+                    .0
+                    .checked_neg()
+                    .expect("attempt to negate with overflow")),
+            ),
+            IntegerIrregularTrait::Sum => gen_impl_trait_sum(
+                type_name,
+                maybe_error_type_name.as_ref(),
+                quote!(iter
+                    .fold(Some(0), |sum: ::core::option::Option<#inner_type>, value| sum?.checked_add(value.0))
+                    .expect("attempt to add with overflow")),
+            ),
+            IntegerIrregularTrait::SerdeSerialize => {
+                gen_impl_trait_serde_serialize(type_name, &generics)
             }
-            IntegerIrregularTrait::Borrow => gen_impl_trait_borrow(type_name, inner_type),
-            IntegerIrregularTrait::Display => gen_impl_trait_dislpay(type_name),
-            IntegerIrregularTrait::SerdeSerialize => gen_impl_trait_serde_serialize(type_name),
             IntegerIrregularTrait::SerdeDeserialize => gen_impl_trait_serde_deserialize(
                 type_name,
+                &generics,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            ),
+            IntegerIrregularTrait::SchemarsJsonSchema => gen_impl_trait_schemars_json_schema(
+                type_name,
+                &generics,
+                inner_type,
+                &schema_refinements,
+            ),
+            IntegerIrregularTrait::SqlxType => gen_impl_trait_sqlx_type(
+                type_name,
+                &generics,
                 inner_type,
                 maybe_error_type_name.as_ref(),
             ),
         })
         .collect()
 }
+
+/// Generate statements, that set `minimum`, `maximum`, etc. of the JSON schema according to the validators.
+pub fn gen_json_schema_constraints<T>(guard: &IntegerGuard<T>) -> TokenStream
+where
+    T: ToTokens,
+{
+    guard
+        .validators()
+        .iter()
+        .filter_map(|validator| {
+            let (field, value) = match validator {
+                IntegerValidator::Min(value) => (quote!(minimum), value),
+                IntegerValidator::Max(value) => (quote!(maximum), value),
+                IntegerValidator::Greater(value) => (quote!(exclusive_minimum), value),
+                IntegerValidator::Less(value) => (quote!(exclusive_maximum), value),
+                IntegerValidator::MultipleOf(value) => (quote!(multiple_of), value),
+                IntegerValidator::With(_) | IntegerValidator::NamedWith(_) => return None,
+            };
+            Some(quote!(
+                schema_object.number().#field = ::core::option::Option::Some(#value as f64);
+            ))
+        })
+        .collect()
+}
@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 
 use crate::{
     common::models::{Guard, RawGuard},
-    common::models::{Kind, SpannedItem},
+    common::models::{Kind, NamedCustomValidator, SpannedItem},
 };
 
 // Sanitizer
@@ -10,20 +10,22 @@ use crate::{
 
 #[derive(Debug)]
 pub enum IntegerSanitizer<T> {
+    Clamp { min: T, max: T },
     With(TokenStream),
-    _Phantom(std::marker::PhantomData<T>),
 }
 
 pub type SpannedIntegerSanitizer<T> = SpannedItem<IntegerSanitizer<T>>;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum IntegerSanitizerKind {
+    Clamp,
     With,
 }
 
 impl std::fmt::Display for IntegerSanitizerKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Clamp => write!(f, "clamp"),
             Self::With => write!(f, "with"),
         }
     }
@@ -34,10 +36,8 @@ impl<T> Kind for IntegerSanitizer<T> {
 
     fn kind(&self) -> IntegerSanitizerKind {
         match self {
+            Self::Clamp { .. } => IntegerSanitizerKind::Clamp,
             Self::With(_) => IntegerSanitizerKind::With,
-            Self::_Phantom(_) => {
-                unreachable!("Kind::kind(): IntegerSanitizer::_Phantom must not be used")
-            }
         }
     }
 }
@@ -49,16 +49,24 @@ impl<T> Kind for IntegerSanitizer<T> {
 pub enum IntegerValidator<T> {
     Min(T),
     Max(T),
+    Greater(T),
+    Less(T),
+    MultipleOf(T),
     With(TokenStream),
+    NamedWith(NamedCustomValidator),
 }
 
 pub type SpannedIntegerValidator<T> = SpannedItem<IntegerValidator<T>>;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum IntegerValidatorKind {
     Min,
     Max,
+    Greater,
+    Less,
+    MultipleOf,
     With,
+    NamedWith(String),
 }
 
 impl std::fmt::Display for IntegerValidatorKind {
@@ -66,7 +74,11 @@ impl std::fmt::Display for IntegerValidatorKind {
         match self {
             Self::Min => write!(f, "min"),
             Self::Max => write!(f, "max"),
+            Self::Greater => write!(f, "greater"),
+            Self::Less => write!(f, "less"),
+            Self::MultipleOf => write!(f, "multiple_of"),
             Self::With => write!(f, "with"),
+            Self::NamedWith(name) => write!(f, "{name}"),
         }
     }
 }
@@ -78,7 +90,13 @@ impl<T> Kind for IntegerValidator<T> {
         match self {
             Self::Min(_) => IntegerValidatorKind::Min,
             Self::Max(_) => IntegerValidatorKind::Max,
+            Self::Greater(_) => IntegerValidatorKind::Greater,
+            Self::Less(_) => IntegerValidatorKind::Less,
+            Self::MultipleOf(_) => IntegerValidatorKind::MultipleOf,
             Self::With(_) => IntegerValidatorKind::With,
+            Self::NamedWith(validator) => {
+                IntegerValidatorKind::NamedWith(validator.name.to_string())
+            }
         }
     }
 }
@@ -97,16 +115,26 @@ pub enum IntegerDeriveTrait {
     Ord,
     FromStr,
     AsRef,
+    Deref,
     Into,
     From,
     TryFrom,
     Hash,
     Borrow,
     Display,
+    Default,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg,
+    Sum,
 
     // // External crates
     SerdeSerialize,
     SerdeDeserialize,
+    SchemarsJsonSchema,
+    SqlxType,
     // Arbitrary,
 }
 
@@ -1,29 +1,38 @@
 use std::fmt::Debug;
+use std::ops::Rem;
 use std::str::FromStr;
 
+use crate::common::models::Attributes;
 use crate::common::parse::{
-    is_comma, parse_nutype_attributes, parse_value_as_number, parse_with_token_stream,
-    split_and_parse,
+    is_comma, parse_clamp_range, parse_multiple_of, parse_named_custom_validator,
+    parse_nutype_attributes, parse_value_as_number, parse_with_token_stream, split_and_parse,
 };
-use proc_macro2::{Span, TokenStream, TokenTree};
+use crate::common::validate::validate_error_options;
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
 
 use super::{
     models::{
         IntegerGuard, IntegerRawGuard, IntegerSanitizer, IntegerValidator, SpannedIntegerSanitizer,
         SpannedIntegerValidator,
     },
-    validate::validate_number_meta,
+    validate::{validate_integer_default_value, validate_number_meta},
 };
 
-pub fn parse_attributes<T>(input: TokenStream) -> Result<IntegerGuard<T>, syn::Error>
+pub fn parse_attributes<T>(input: TokenStream) -> Result<Attributes<IntegerGuard<T>>, syn::Error>
 where
-    T: FromStr + PartialOrd + Clone,
+    T: FromStr + PartialOrd + Clone + Default + Rem<Output = T>,
     <T as FromStr>::Err: Debug,
 {
-    parse_raw_attributes(input).and_then(validate_number_meta)
+    let raw_attributes = parse_raw_attributes(input)?;
+    validate_error_options(&raw_attributes, |v| {
+        matches!(v.item, IntegerValidator::With(_))
+    })?;
+    let attributes = raw_attributes.try_map_guard(validate_number_meta)?;
+    validate_integer_default_value(&attributes)?;
+    Ok(attributes)
 }
 
-fn parse_raw_attributes<T>(input: TokenStream) -> Result<IntegerRawGuard<T>, syn::Error>
+fn parse_raw_attributes<T>(input: TokenStream) -> Result<Attributes<IntegerRawGuard<T>>, syn::Error>
 where
     T: FromStr,
     <T as FromStr>::Err: Debug,
@@ -51,6 +60,13 @@ where
     let token = token_iter.next();
     if let Some(TokenTree::Ident(ident)) = token {
         match ident.to_string().as_ref() {
+            "clamp" => {
+                let (min, max) = parse_clamp_range(ident, token_iter.cloned())?;
+                Ok(SpannedIntegerSanitizer {
+                    span: ident.span(),
+                    item: IntegerSanitizer::Clamp { min, max },
+                })
+            }
             "with" => {
                 // Preserve the rest as `custom_sanitizer_fn`
                 let stream = parse_with_token_stream(token_iter, ident.span())?;
@@ -110,11 +126,46 @@ where
                 };
                 Ok(parsed_validator)
             }
+            "greater" => {
+                let (value, _iter) = parse_value_as_number(token_iter)?;
+                let validator = IntegerValidator::Greater(value);
+                let parsed_validator = SpannedIntegerValidator {
+                    span: ident.span(),
+                    item: validator,
+                };
+                Ok(parsed_validator)
+            }
+            "less" => {
+                let (value, _iter) = parse_value_as_number(token_iter)?;
+                let validator = IntegerValidator::Less(value);
+                let parsed_validator = SpannedIntegerValidator {
+                    span: ident.span(),
+                    item: validator,
+                };
+                Ok(parsed_validator)
+            }
+            "multiple_of" => {
+                let (step, _epsilon) = parse_multiple_of(&ident, token_iter, false)?;
+                let validator = IntegerValidator::MultipleOf(step);
+                let parsed_validator = SpannedIntegerValidator {
+                    span: ident.span(),
+                    item: validator,
+                };
+                Ok(parsed_validator)
+            }
             "with" => {
                 let rest_tokens: Vec<_> = token_iter.collect();
-                let stream = parse_with_token_stream(rest_tokens.iter(), ident.span())?;
+                let validator = match rest_tokens.as_slice() {
+                    [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+                        let named = parse_named_custom_validator(group, ident.span())?;
+                        IntegerValidator::NamedWith(named)
+                    }
+                    _ => {
+                        let stream = parse_with_token_stream(rest_tokens.iter(), ident.span())?;
+                        IntegerValidator::With(stream)
+                    }
+                };
                 let span = ident.span();
-                let validator = IntegerValidator::With(stream);
                 Ok(SpannedIntegerValidator {
                     span,
                     item: validator,
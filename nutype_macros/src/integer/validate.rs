@@ -1,26 +1,35 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, ops::Rem, str::FromStr};
 
 use proc_macro2::Span;
+use quote::ToTokens;
 
 use crate::{
-    common::models::{DeriveTrait, NormalDeriveTrait, SpannedDeriveTrait},
-    common::validate::validate_duplicates,
+    common::models::{
+        Attributes, DeriveTrait, IntegerType, Kind, NamedCustomValidator, NormalDeriveTrait,
+        SpannedDeriveTrait,
+    },
+    common::parse::parse_literal_number,
+    common::validate::{
+        gen_default_value_error, validate_duplicates, validate_error_variant_names,
+    },
 };
 
+use super::gen::error::error_variant_name;
 use super::models::{
     IntegerDeriveTrait, IntegerGuard, IntegerRawGuard, IntegerSanitizer, IntegerValidator,
-    SpannedIntegerSanitizer, SpannedIntegerValidator,
+    IntegerValidatorKind, SpannedIntegerSanitizer, SpannedIntegerValidator,
 };
 
 pub fn validate_number_meta<T>(raw_meta: IntegerRawGuard<T>) -> Result<IntegerGuard<T>, syn::Error>
 where
-    T: PartialOrd + Clone,
+    T: PartialOrd + Clone + Default,
 {
     let IntegerRawGuard {
         sanitizers,
         validators,
     } = raw_meta;
 
+    validate_clamp(&sanitizers, &validators)?;
     let validators = validate_validators(validators)?;
     let sanitizers = validate_sanitizers(sanitizers)?;
 
@@ -34,16 +43,62 @@ where
     }
 }
 
+/// Verifies at compile time that a literal default value (e.g. `default = 18`)
+/// satisfies the validators. Sanitizers may change the value, so if there are any,
+/// the check is left to `default()`, that panics on an invalid value.
+pub fn validate_integer_default_value<T>(
+    attributes: &Attributes<IntegerGuard<T>>,
+) -> Result<(), syn::Error>
+where
+    T: FromStr + PartialOrd + Clone + Default + Rem<Output = T>,
+{
+    let (default_value, validators) = match (&attributes.default, &attributes.guard) {
+        (
+            Some(default_value),
+            IntegerGuard::WithValidation {
+                sanitizers,
+                validators,
+            },
+        ) if sanitizers.is_empty() => (default_value, validators),
+        _ => return Ok(()),
+    };
+    let value: T = match parse_literal_number(default_value) {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+    for validator in validators {
+        let is_violated = match validator {
+            IntegerValidator::Min(min) => value < *min,
+            IntegerValidator::Max(max) => value > *max,
+            IntegerValidator::Greater(greater) => value <= *greater,
+            IntegerValidator::Less(less) => value >= *less,
+            IntegerValidator::MultipleOf(multiple_of) => {
+                value.clone() % multiple_of.clone() != T::default()
+            }
+            _ => false,
+        };
+        if is_violated {
+            return Err(gen_default_value_error(default_value, validator));
+        }
+    }
+    Ok(())
+}
+
 fn validate_validators<T>(
     validators: Vec<SpannedIntegerValidator<T>>,
 ) -> Result<Vec<IntegerValidator<T>>, syn::Error>
 where
-    T: PartialOrd + Clone,
+    T: PartialOrd + Clone + Default,
 {
     validate_duplicates(&validators, |kind| {
         format!("Duplicated validator `{kind}`.\nYou're a great engineer, but don't forget to take care of yourself!")
     })?;
 
+    validate_error_variant_names(&validators, error_variant_name, |v| match v {
+        IntegerValidator::NamedWith(NamedCustomValidator { name, .. }) => Some(name),
+        _ => None,
+    })?;
+
     // max VS min
     let maybe_min = validators
         .iter()
@@ -67,10 +122,98 @@ where
         }
     }
 
+    // min VS greater, max VS less
+    for (inclusive, exclusive) in [
+        (IntegerValidatorKind::Min, IntegerValidatorKind::Greater),
+        (IntegerValidatorKind::Max, IntegerValidatorKind::Less),
+    ] {
+        let has_inclusive = validators.iter().any(|v| v.kind() == inclusive);
+        let maybe_exclusive = validators.iter().find(|v| v.kind() == exclusive);
+        if let (true, Some(exclusive_validator)) = (has_inclusive, maybe_exclusive) {
+            let msg = format!(
+                "`{inclusive}` and `{exclusive}` cannot be used together.\nOne bound on each side is enough!"
+            );
+            let err = syn::Error::new(exclusive_validator.span, msg);
+            return Err(err);
+        }
+    }
+
+    // lower VS upper bound, when at least one of them is exclusive
+    let maybe_lower = validators.iter().find_map(|v| match &v.item {
+        IntegerValidator::Min(min) => Some((v.kind(), min.clone())),
+        IntegerValidator::Greater(greater) => Some((v.kind(), greater.clone())),
+        _ => None,
+    });
+    let maybe_upper = validators.iter().find_map(|v| match &v.item {
+        IntegerValidator::Max(max) => Some((v.span, v.kind(), max.clone())),
+        IntegerValidator::Less(less) => Some((v.span, v.kind(), less.clone())),
+        _ => None,
+    });
+    if let (Some((lower_kind, lower)), Some((upper_span, upper_kind, upper))) =
+        (maybe_lower, maybe_upper)
+    {
+        let is_exclusive =
+            lower_kind == IntegerValidatorKind::Greater || upper_kind == IntegerValidatorKind::Less;
+        if is_exclusive && lower >= upper {
+            let msg = format!(
+                "`{lower_kind}` must be less than `{upper_kind}`, otherwise no value can pass the validation.\nSometimes we all need a little break."
+            );
+            let err = syn::Error::new(upper_span, msg);
+            return Err(err);
+        }
+    }
+
+    // multiple_of
+    for validator in &validators {
+        if let IntegerValidator::MultipleOf(step) = &validator.item {
+            if *step <= T::default() {
+                let msg = "`multiple_of` must be greater than zero.";
+                return Err(syn::Error::new(validator.span, msg));
+            }
+        }
+    }
+
     let validators: Vec<_> = validators.into_iter().map(|v| v.item).collect();
     Ok(validators)
 }
 
+/// Ensures that `clamp` produces values, that pass `min` and `max` validators.
+fn validate_clamp<T>(
+    sanitizers: &[SpannedIntegerSanitizer<T>],
+    validators: &[SpannedIntegerValidator<T>],
+) -> Result<(), syn::Error>
+where
+    T: PartialOrd,
+{
+    for sanitizer in sanitizers {
+        if let IntegerSanitizer::Clamp { min, max } = &sanitizer.item {
+            if min > max {
+                let msg = "The lower bound of `clamp` cannot be greater than the upper bound.\nIt's upside down!";
+                return Err(syn::Error::new(sanitizer.span, msg));
+            }
+            for validator in validators {
+                let msg = match &validator.item {
+                    IntegerValidator::Min(min_bound) if min < min_bound => {
+                        "The lower bound of `clamp` is less than `min`, so clamped values can still be rejected by the validation."
+                    }
+                    IntegerValidator::Max(max_bound) if max > max_bound => {
+                        "The upper bound of `clamp` is greater than `max`, so clamped values can still be rejected by the validation."
+                    }
+                    IntegerValidator::Greater(greater) if min <= greater => {
+                        "The lower bound of `clamp` is not greater than `greater`, so clamped values can still be rejected by the validation."
+                    }
+                    IntegerValidator::Less(less) if max >= less => {
+                        "The upper bound of `clamp` is not less than `less`, so clamped values can still be rejected by the validation."
+                    }
+                    _ => continue,
+                };
+                return Err(syn::Error::new(sanitizer.span, msg));
+            }
+        }
+    }
+    Ok(())
+}
+
 fn validate_sanitizers<T>(
     sanitizers: Vec<SpannedIntegerSanitizer<T>>,
 ) -> Result<Vec<IntegerSanitizer<T>>, syn::Error>
@@ -88,6 +231,7 @@ where
 pub fn validate_integer_derive_traits(
     spanned_derive_traits: Vec<SpannedDeriveTrait>,
     has_validation: bool,
+    integer_type: IntegerType,
 ) -> Result<HashSet<IntegerDeriveTrait>, syn::Error> {
     let mut traits = HashSet::with_capacity(24);
 
@@ -97,8 +241,12 @@ pub fn validate_integer_derive_traits(
                 traits.extend(unfold_asterisk_traits(has_validation));
             }
             DeriveTrait::Normal(normal_trait) => {
-                let string_derive_trait =
-                    to_integer_derive_trait(normal_trait, has_validation, spanned_trait.span)?;
+                let string_derive_trait = to_integer_derive_trait(
+                    normal_trait,
+                    has_validation,
+                    integer_type,
+                    spanned_trait.span,
+                )?;
                 traits.insert(string_derive_trait);
             }
         };
@@ -133,11 +281,13 @@ fn unfold_asterisk_traits(has_validation: bool) -> impl Iterator<Item = IntegerD
 fn to_integer_derive_trait(
     tr: NormalDeriveTrait,
     has_validation: bool,
+    integer_type: IntegerType,
     span: Span,
 ) -> Result<IntegerDeriveTrait, syn::Error> {
     match tr {
         NormalDeriveTrait::Debug => Ok(IntegerDeriveTrait::Debug),
         NormalDeriveTrait::Display => Ok(IntegerDeriveTrait::Display),
+        NormalDeriveTrait::Default => Ok(IntegerDeriveTrait::Default),
         NormalDeriveTrait::Clone => Ok(IntegerDeriveTrait::Clone),
         NormalDeriveTrait::PartialEq => Ok(IntegerDeriveTrait::PartialEq),
         NormalDeriveTrait::Eq => Ok(IntegerDeriveTrait::Eq),
@@ -146,11 +296,30 @@ fn to_integer_derive_trait(
         NormalDeriveTrait::Into => Ok(IntegerDeriveTrait::Into),
         NormalDeriveTrait::FromStr => Ok(IntegerDeriveTrait::FromStr),
         NormalDeriveTrait::AsRef => Ok(IntegerDeriveTrait::AsRef),
+        NormalDeriveTrait::Deref => Ok(IntegerDeriveTrait::Deref),
+        NormalDeriveTrait::Add => Ok(IntegerDeriveTrait::Add),
+        NormalDeriveTrait::Sub => Ok(IntegerDeriveTrait::Sub),
+        NormalDeriveTrait::Mul => Ok(IntegerDeriveTrait::Mul),
+        NormalDeriveTrait::Div => Ok(IntegerDeriveTrait::Div),
+        NormalDeriveTrait::Sum => Ok(IntegerDeriveTrait::Sum),
+        NormalDeriveTrait::Neg => {
+            if integer_type.is_signed() {
+                Ok(IntegerDeriveTrait::Neg)
+            } else {
+                let msg = format!(
+                    "#[nutype] cannot derive `Neg` trait for unsigned `{}` inner type.",
+                    integer_type.to_token_stream()
+                );
+                Err(syn::Error::new(span, msg))
+            }
+        }
         NormalDeriveTrait::Hash => Ok(IntegerDeriveTrait::Hash),
         NormalDeriveTrait::Borrow => Ok(IntegerDeriveTrait::Borrow),
         NormalDeriveTrait::Copy => Ok(IntegerDeriveTrait::Copy),
         NormalDeriveTrait::SerdeSerialize => Ok(IntegerDeriveTrait::SerdeSerialize),
         NormalDeriveTrait::SerdeDeserialize => Ok(IntegerDeriveTrait::SerdeDeserialize),
+        NormalDeriveTrait::SchemarsJsonSchema => Ok(IntegerDeriveTrait::SchemarsJsonSchema),
+        NormalDeriveTrait::SqlxType => Ok(IntegerDeriveTrait::SqlxType),
         NormalDeriveTrait::From => {
             if has_validation {
                 Err(syn::Error::new(span, "#[nutype] cannot derive `From` trait, because there is validation defined. Use `TryFrom` instead."))
@@ -1,14 +1,17 @@
+mod any;
 mod common;
 mod float;
 mod integer;
 mod string;
 
-use std::{fmt::Debug, str::FromStr};
+use std::{fmt::Debug, ops::Rem, str::FromStr};
 
+use any::validate::validate_any_derive_traits;
 use common::models::{
     FloatType, InnerType, IntegerType, NewtypeMeta, SpannedDeriveTrait, TypeName,
 };
 use common::parse::meta::parse_meta;
+use common::validate::validate_default_value;
 use float::validate::validate_float_derive_traits;
 use integer::validate::validate_integer_derive_traits;
 use proc_macro2::TokenStream;
@@ -33,6 +36,7 @@ fn expand_nutype(
     let NewtypeMeta {
         doc_attrs,
         type_name,
+        generics,
         inner_type,
         vis,
         derive_traits,
@@ -40,10 +44,11 @@ fn expand_nutype(
 
     match inner_type {
         InnerType::String => {
-            let meta = string::parse::parse_attributes(attrs)?;
-            let traits = validate_string_derive_traits(&meta, derive_traits)?;
+            let attributes = string::parse::parse_attributes(attrs)?;
+            validate_default_value(&derive_traits, &attributes)?;
+            let traits = validate_string_derive_traits(&attributes.guard, derive_traits)?;
             Ok(gen_nutype_for_string(
-                doc_attrs, traits, vis, &type_name, meta,
+                doc_attrs, traits, vis, &type_name, attributes,
             ))
         }
         InnerType::Integer(tp) => {
@@ -84,6 +89,18 @@ fn expand_nutype(
                 FloatType::F64 => parse_float_attrs_and_gen::<f64>(params),
             }
         }
+        InnerType::Any(tp) => {
+            let attributes = any::parse::parse_attributes(attrs)?;
+            validate_default_value(&derive_traits, &attributes)?;
+            let traits = validate_any_derive_traits(
+                derive_traits,
+                attributes.guard.has_validation(),
+                &generics,
+            )?;
+            Ok(any::gen::gen_nutype_for_any(
+                doc_attrs, vis, &tp, &type_name, &generics, attributes, traits,
+            ))
+        }
     }
 }
 
@@ -100,7 +117,7 @@ fn parse_integer_attrs_and_gen<T>(
     params: NumberParams<IntegerType>,
 ) -> Result<TokenStream, syn::Error>
 where
-    T: FromStr + ToTokens + PartialOrd + Clone,
+    T: FromStr + ToTokens + PartialOrd + Clone + Default + Rem<Output = T>,
     <T as FromStr>::Err: Debug,
 {
     let NumberParams {
@@ -111,16 +128,18 @@ where
         attrs,
         derive_traits,
     } = params;
-    let meta = integer::parse::parse_attributes::<T>(attrs)?;
-    let traits = validate_integer_derive_traits(derive_traits, meta.has_validation())?;
+    let attributes = integer::parse::parse_attributes::<T>(attrs)?;
+    validate_default_value(&derive_traits, &attributes)?;
+    let traits =
+        validate_integer_derive_traits(derive_traits, attributes.guard.has_validation(), tp)?;
     Ok(integer::gen::gen_nutype_for_integer(
-        doc_attrs, vis, tp, &type_name, meta, traits,
+        doc_attrs, vis, tp, &type_name, attributes, traits,
     ))
 }
 
 fn parse_float_attrs_and_gen<T>(params: NumberParams<FloatType>) -> Result<TokenStream, syn::Error>
 where
-    T: FromStr + ToTokens + PartialOrd + Clone,
+    T: FromStr + ToTokens + PartialOrd + Clone + Default + Into<f64>,
     <T as FromStr>::Err: Debug,
 {
     let NumberParams {
@@ -131,9 +150,10 @@ where
         attrs,
         derive_traits,
     } = params;
-    let meta = float::parse::parse_attributes::<T>(attrs)?;
-    let traits = validate_float_derive_traits(derive_traits, meta.has_validation())?;
+    let attributes = float::parse::parse_attributes::<T>(attrs, tp)?;
+    validate_default_value(&derive_traits, &attributes)?;
+    let traits = validate_float_derive_traits(&attributes.guard, derive_traits)?;
     Ok(float::gen::gen_nutype_for_float(
-        doc_attrs, vis, tp, &type_name, meta, traits,
+        doc_attrs, vis, tp, &type_name, attributes, traits,
     ))
 }
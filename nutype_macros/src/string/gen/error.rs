@@ -1,10 +1,13 @@
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 
 use crate::{
     common::{
-        gen::error::{gen_error_type_name, gen_impl_error_trait},
-        models::TypeName,
+        gen::error::{
+            gen_bound_error_variant, gen_error_type_name, gen_impl_error_trait,
+            gen_named_validator_message,
+        },
+        models::{ErrorOptions, NamedCustomValidator, TypeName},
     },
     string::models::StringValidator,
 };
@@ -12,10 +15,12 @@ use crate::{
 pub fn gen_validation_error_type(
     type_name: &TypeName,
     validators: &[StringValidator],
+    error_options: &ErrorOptions,
 ) -> TokenStream {
+    let detailed_errors = error_options.detailed;
     let error_type_name = gen_error_type_name(type_name);
-    let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(&error_type_name, validators);
+    let definition = gen_definition(&error_type_name, validators, detailed_errors);
+    let impl_display_trait = gen_impl_display_trait(&error_type_name, validators, error_options);
     let impl_error_trait = gen_impl_error_trait(&error_type_name);
 
     quote! {
@@ -27,22 +32,36 @@ pub fn gen_validation_error_type(
     }
 }
 
-fn gen_definition(error_type_name: &Ident, validators: &[StringValidator]) -> TokenStream {
+fn gen_definition(
+    error_type_name: &Ident,
+    validators: &[StringValidator],
+    detailed_errors: bool,
+) -> TokenStream {
+    let len_variant = |variant, bound_name| {
+        gen_bound_error_variant(variant, bound_name, quote!(usize), detailed_errors)
+    };
+
     let error_variants: TokenStream = validators
         .iter()
         .map(|validator| match validator {
-            StringValidator::MaxLen(_len) => {
-                quote!(TooLong,)
-            }
-            StringValidator::MinLen(_len) => {
-                quote!(TooShort,)
-            }
+            StringValidator::MaxLen(_len) => len_variant("TooLong", "max"),
+            StringValidator::MinLen(_len) => len_variant("TooShort", "min"),
+            StringValidator::CharLenMax(_len) => len_variant("TooLongChars", "max"),
+            StringValidator::CharLenMin(_len) => len_variant("TooShortChars", "min"),
+            StringValidator::GraphemeLenMax(_len) => len_variant("TooLongGraphemes", "max"),
+            StringValidator::GraphemeLenMin(_len) => len_variant("TooShortGraphemes", "min"),
             StringValidator::NotEmpty => {
                 quote!(Empty,)
             }
             StringValidator::With(_) => {
                 quote!(Invalid,)
             }
+            StringValidator::NamedWith(NamedCustomValidator { name, .. }) => {
+                quote!(#name,)
+            }
+            StringValidator::Regex(_) => {
+                quote!(RegexMismatch,)
+            }
         })
         .collect();
 
@@ -53,20 +72,73 @@ fn gen_definition(error_type_name: &Ident, validators: &[StringValidator]) -> To
     }
 }
 
-fn gen_impl_display_trait(error_type_name: &Ident, validators: &[StringValidator]) -> TokenStream {
-    let match_arms = validators.iter().map(|validator| match validator {
+fn gen_impl_display_trait(
+    error_type_name: &Ident,
+    validators: &[StringValidator],
+    error_options: &ErrorOptions,
+) -> TokenStream {
+    let detailed_errors = error_options.detailed;
+    let match_arms = validators.iter().map(|validator| {
+        if let Some(message) = error_options.message_for(validator) {
+            let variant = error_variant_name(validator);
+            return quote! {
+                #error_type_name::#variant { .. } => f.write_str(#message)
+            };
+        }
+
+        match validator {
+        StringValidator::MaxLen(_len) if detailed_errors => quote! {
+             #error_type_name::TooLong { max, actual } => write!(f, "too long: expected at most {max} bytes, got {actual}")
+        },
         StringValidator::MaxLen(_len) => quote! {
              #error_type_name::TooLong => write!(f, "too long")
         },
+        StringValidator::MinLen(_len) if detailed_errors => quote! {
+             #error_type_name::TooShort { min, actual } => write!(f, "too short: expected at least {min} bytes, got {actual}")
+        },
         StringValidator::MinLen(_len) => quote! {
              #error_type_name::TooShort => write!(f, "too short")
         },
+        StringValidator::CharLenMax(_len) if detailed_errors => quote! {
+             #error_type_name::TooLongChars { max, actual } => write!(f, "too long: expected at most {max} chars, got {actual}")
+        },
+        StringValidator::CharLenMax(_len) => quote! {
+             #error_type_name::TooLongChars => write!(f, "too long (in chars)")
+        },
+        StringValidator::CharLenMin(_len) if detailed_errors => quote! {
+             #error_type_name::TooShortChars { min, actual } => write!(f, "too short: expected at least {min} chars, got {actual}")
+        },
+        StringValidator::CharLenMin(_len) => quote! {
+             #error_type_name::TooShortChars => write!(f, "too short (in chars)")
+        },
+        StringValidator::GraphemeLenMax(_len) if detailed_errors => quote! {
+             #error_type_name::TooLongGraphemes { max, actual } => write!(f, "too long: expected at most {max} graphemes, got {actual}")
+        },
+        StringValidator::GraphemeLenMax(_len) => quote! {
+             #error_type_name::TooLongGraphemes => write!(f, "too long (in graphemes)")
+        },
+        StringValidator::GraphemeLenMin(_len) if detailed_errors => quote! {
+             #error_type_name::TooShortGraphemes { min, actual } => write!(f, "too short: expected at least {min} graphemes, got {actual}")
+        },
+        StringValidator::GraphemeLenMin(_len) => quote! {
+             #error_type_name::TooShortGraphemes => write!(f, "too short (in graphemes)")
+        },
         StringValidator::NotEmpty => quote! {
              #error_type_name::Empty => write!(f, "empty")
         },
         StringValidator::With(_) => quote! {
              #error_type_name::Invalid => write!(f, "invalid")
         },
+        StringValidator::NamedWith(NamedCustomValidator { name, .. }) => {
+            let message = gen_named_validator_message(name);
+            quote! {
+                 #error_type_name::#name => f.write_str(#message)
+            }
+        }
+        StringValidator::Regex(_) => quote! {
+             #error_type_name::RegexMismatch => write!(f, "regex mismatch")
+        },
+    }
     });
 
     quote! {
@@ -79,3 +151,19 @@ fn gen_impl_display_trait(error_type_name: &Ident, validators: &[StringValidator
         }
     }
 }
+
+pub fn error_variant_name(validator: &StringValidator) -> Ident {
+    let name = match validator {
+        StringValidator::MaxLen(_) => "TooLong",
+        StringValidator::MinLen(_) => "TooShort",
+        StringValidator::CharLenMax(_) => "TooLongChars",
+        StringValidator::CharLenMin(_) => "TooShortChars",
+        StringValidator::GraphemeLenMax(_) => "TooLongGraphemes",
+        StringValidator::GraphemeLenMin(_) => "TooShortGraphemes",
+        StringValidator::NotEmpty => "Empty",
+        StringValidator::With(_) => "Invalid",
+        StringValidator::NamedWith(NamedCustomValidator { name, .. }) => return name.clone(),
+        StringValidator::Regex(_) => "RegexMismatch",
+    };
+    Ident::new(name, Span::call_site())
+}
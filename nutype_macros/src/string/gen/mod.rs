@@ -5,20 +5,28 @@ use std::collections::HashSet;
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::Attribute;
+use syn::{Attribute, Generics};
 
 use crate::{
     common::{
         gen::{
-            error::gen_error_type_name, gen_module_name_for_type, gen_reimports,
-            traits::GeneratedTraits, type_custom_closure,
+            error::{gen_bound_error, gen_error_type_name, gen_error_type_path},
+            gen_constraint_rule, gen_custom_validator_check, gen_impl_constraints,
+            gen_impl_nutype_trait, gen_module_name_for_type, gen_named_custom_validator_check,
+            gen_new_unchecked, gen_reimports, gen_report_error, gen_sanitize_and_validate_fns,
+            gen_validate_fn_from_checks,
+            traits::{gen_json_schema_refinements, GeneratedTraits},
+            type_custom_closure, ConstraintValue,
         },
-        models::TypeName,
+        models::{Attributes, ErrorOptions, Kind, NamedCustomValidator, TypeName},
     },
     string::models::{StringSanitizer, StringValidator},
 };
 
-use self::{error::gen_validation_error_type, traits::gen_traits};
+use self::{
+    error::gen_validation_error_type,
+    traits::{gen_json_schema_constraints, gen_traits},
+};
 
 use super::models::{StringDeriveTrait, StringGuard};
 
@@ -27,28 +35,55 @@ pub fn gen_nutype_for_string(
     traits: HashSet<StringDeriveTrait>,
     vis: syn::Visibility,
     type_name: &TypeName,
-    guard: StringGuard,
+    attributes: Attributes<StringGuard>,
 ) -> TokenStream {
+    let Attributes {
+        guard,
+        error_options,
+        default,
+        new_unchecked,
+    } = attributes;
     let module_name = gen_module_name_for_type(type_name);
-    let implementation = gen_string_implementation(type_name, &guard);
+    let implementation =
+        gen_string_implementation(type_name, &guard, &error_options, new_unchecked);
 
-    let maybe_error_type_name: Option<Ident> = match guard {
+    let maybe_error_type_name: Option<syn::Path> = match guard {
         StringGuard::WithoutValidation { .. } => None,
-        StringGuard::WithValidation { .. } => Some(gen_error_type_name(type_name)),
+        StringGuard::WithValidation { .. } => Some(gen_error_type_path(type_name, &error_options)),
+    };
+    let impl_nutype_trait = gen_impl_nutype_trait(
+        type_name,
+        quote!(String),
+        &Generics::default(),
+        maybe_error_type_name.as_ref(),
+    );
+
+    // A custom error type is defined by the user, so only the generated one needs to be reimported.
+    let maybe_reimported_error_type_name: Option<Ident> = match guard {
+        StringGuard::WithValidation { .. } if error_options.custom_type.is_none() => {
+            Some(gen_error_type_name(type_name))
+        }
+        _ => None,
     };
 
     let reimports = gen_reimports(
         vis,
         type_name,
         &module_name,
-        maybe_error_type_name.as_ref(),
+        maybe_reimported_error_type_name.as_ref(),
         None,
     );
 
     let GeneratedTraits {
         derive_standard_traits,
         implement_traits,
-    } = gen_traits(type_name, maybe_error_type_name, traits);
+    } = gen_traits(
+        type_name,
+        maybe_error_type_name,
+        default,
+        gen_json_schema_refinements(&doc_attrs, gen_json_schema_constraints(&guard)),
+        traits,
+    );
 
     quote!(
         #[doc(hidden)]
@@ -60,30 +95,106 @@ pub fn gen_nutype_for_string(
             pub struct #type_name(String);
 
             #implementation
+            #impl_nutype_trait
             #implement_traits
         }
         #reimports
     )
 }
 
-pub fn gen_string_implementation(type_name: &TypeName, meta: &StringGuard) -> TokenStream {
+pub fn gen_string_implementation(
+    type_name: &TypeName,
+    meta: &StringGuard,
+    error_options: &ErrorOptions,
+    new_unchecked: bool,
+) -> TokenStream {
     let methods = gen_impl_methods(type_name);
     let convert_implementation = match meta {
         StringGuard::WithoutValidation { sanitizers } => {
-            gen_new_without_validation(type_name, sanitizers)
+            gen_new_without_validation(type_name, sanitizers, new_unchecked)
         }
         StringGuard::WithValidation {
             sanitizers,
             validators,
-        } => gen_new_and_with_validation(type_name, sanitizers, validators),
+        } => gen_new_and_with_validation(
+            type_name,
+            sanitizers,
+            validators,
+            error_options,
+            new_unchecked,
+        ),
     };
 
+    let constraints = gen_constraints(type_name, meta);
+    let maybe_regex = gen_regex_static(meta.validators());
+
     quote! {
+        #maybe_regex
         #convert_implementation
+        #constraints
         #methods
     }
 }
 
+/// The regex is compiled only once, on the first validation, and is shared by all validation
+/// functions of the type (e.g. `validate` and `validate_all` for `collect_errors`).
+/// The pattern is already verified at expansion time, so `expect()` never panics.
+fn gen_regex_static(validators: &[StringValidator]) -> TokenStream {
+    validators
+        .iter()
+        .find_map(|validator| match validator {
+            StringValidator::Regex(pattern) => Some(quote!(
+                static __NUTYPE_REGEX__: ::std::sync::OnceLock<::regex::Regex> = ::std::sync::OnceLock::new();
+
+                fn __nutype_regex__() -> &'static ::regex::Regex {
+                    __NUTYPE_REGEX__.get_or_init(|| {
+                        ::regex::Regex::new(#pattern).expect("#[nutype] regex is expected to be valid")
+                    })
+                }
+            )),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+fn gen_constraints(type_name: &TypeName, meta: &StringGuard) -> TokenStream {
+    let sanitizers = meta
+        .sanitizers()
+        .iter()
+        .map(|sanitizer| gen_constraint_rule(sanitizer.kind(), &[]))
+        .collect();
+    let validators = meta
+        .validators()
+        .iter()
+        .map(|validator| match validator {
+            StringValidator::MinLen(len)
+            | StringValidator::MaxLen(len)
+            | StringValidator::CharLenMin(len)
+            | StringValidator::CharLenMax(len)
+            | StringValidator::GraphemeLenMin(len)
+            | StringValidator::GraphemeLenMax(len) => {
+                gen_constraint_rule(validator.kind(), &[ConstraintValue::UInt.gen(len)])
+            }
+            StringValidator::Regex(pattern) => {
+                gen_constraint_rule(validator.kind(), &[ConstraintValue::Str.gen(pattern)])
+            }
+            StringValidator::NotEmpty | StringValidator::With(_) => {
+                gen_constraint_rule(validator.kind(), &[])
+            }
+            StringValidator::NamedWith(NamedCustomValidator { name, .. }) => {
+                gen_constraint_rule("with", &[ConstraintValue::Str.gen(name.to_string())])
+            }
+        })
+        .collect();
+    gen_impl_constraints(
+        type_name,
+        quote!(String),
+        &Generics::default(),
+        sanitizers,
+        validators,
+    )
+}
+
 fn gen_impl_methods(type_name: &TypeName) -> TokenStream {
     quote! {
         impl #type_name {
@@ -94,15 +205,41 @@ fn gen_impl_methods(type_name: &TypeName) -> TokenStream {
     }
 }
 
-fn gen_new_without_validation(type_name: &TypeName, sanitizers: &[StringSanitizer]) -> TokenStream {
+fn gen_new_without_validation(
+    type_name: &TypeName,
+    sanitizers: &[StringSanitizer],
+    new_unchecked: bool,
+) -> TokenStream {
     let sanitize = gen_string_sanitize_fn(sanitizers);
+    let new_unchecked = if new_unchecked {
+        gen_new_unchecked(
+            type_name,
+            quote!(raw_value: impl Into<String>),
+            quote!(raw_value.into()),
+            false,
+        )
+    } else {
+        quote!()
+    };
+
+    let sanitize_and_validate = gen_sanitize_and_validate_fns(
+        quote!(String),
+        quote!(raw_value: impl Into<String>),
+        quote!(raw_value.into()),
+        quote!(&str),
+        sanitize,
+        None,
+    );
 
     quote!(
         impl #type_name {
             pub fn new(raw_value: impl Into<String>) -> Self {
-                #sanitize
-                #type_name(sanitize(raw_value.into()))
+                #type_name(Self::sanitize(raw_value.into()))
             }
+
+            #sanitize_and_validate
+
+            #new_unchecked
         }
     )
 }
@@ -111,26 +248,68 @@ fn gen_new_and_with_validation(
     type_name: &TypeName,
     sanitizers: &[StringSanitizer],
     validators: &[StringValidator],
+    error_options: &ErrorOptions,
+    new_unchecked: bool,
 ) -> TokenStream {
     let sanitize = gen_string_sanitize_fn(sanitizers);
-    let validation_error = gen_validation_error_type(type_name, validators);
-    let error_type_name = gen_error_type_name(type_name);
-    let validate = gen_string_validate_fn(type_name, validators);
+    let validation_error = if error_options.custom_type.is_none() {
+        gen_validation_error_type(type_name, validators, error_options)
+    } else {
+        quote!()
+    };
+    let error_type_name = gen_error_type_path(type_name, error_options);
+    let validate = gen_string_validate_fn(type_name, validators, error_options, false);
+
+    let new_collecting = if error_options.collect {
+        let validate_all = gen_string_validate_fn(type_name, validators, error_options, true);
+        quote!(
+            /// Same as `new()`, but instead of stopping at the first error, returns all the violated validation rules.
+            pub fn new_collecting(raw_value: impl Into<String>) -> ::core::result::Result<Self, ::std::vec::Vec<#error_type_name>> {
+                #validate_all
+
+                let sanitized_value = Self::sanitize(raw_value.into());
+                validate_all(&sanitized_value)?;
+                Ok(#type_name(sanitized_value))
+            }
+        )
+    } else {
+        quote!()
+    };
+
+    let new_unchecked = if new_unchecked {
+        gen_new_unchecked(
+            type_name,
+            quote!(raw_value: impl Into<String>),
+            quote!(raw_value.into()),
+            true,
+        )
+    } else {
+        quote!()
+    };
+
+    let sanitize_and_validate = gen_sanitize_and_validate_fns(
+        quote!(String),
+        quote!(raw_value: impl Into<String>),
+        quote!(raw_value.into()),
+        quote!(&str),
+        sanitize,
+        Some((&error_type_name, quote!(#validate validate(value)))),
+    );
 
     quote!(
         #validation_error
 
         impl #type_name {
             pub fn new(raw_value: impl Into<String>) -> ::core::result::Result<Self, #error_type_name> {
-                // Keep sanitize() and validate() within new() so they do not overlap with outer
-                // scope imported with `use super::*`.
-                #sanitize
-                #validate
-
-                let sanitized_value = sanitize(raw_value.into());
-                validate(&sanitized_value)?;
+                let sanitized_value = Self::sanitize(raw_value.into());
+                Self::validate_sanitized(&sanitized_value)?;
                 Ok(#type_name(sanitized_value))
             }
+
+            #sanitize_and_validate
+
+            #new_collecting
+            #new_unchecked
         }
     )
 }
@@ -175,49 +354,117 @@ pub fn gen_string_sanitize_fn(sanitizers: &[StringSanitizer]) -> TokenStream {
     )
 }
 
-pub fn gen_string_validate_fn(type_name: &TypeName, validators: &[StringValidator]) -> TokenStream {
-    let error_name = gen_error_type_name(type_name);
+pub fn gen_string_validate_fn(
+    type_name: &TypeName,
+    validators: &[StringValidator],
+    error_options: &ErrorOptions,
+    collect: bool,
+) -> TokenStream {
+    let error_name = gen_error_type_path(type_name, error_options);
+    let detailed_errors = error_options.detailed;
+    let report_error = |error| gen_report_error(error, collect);
+    let bound_error = |variant, bound_name, bound: &usize, actual| {
+        gen_bound_error(
+            &error_name,
+            variant,
+            bound_name,
+            bound,
+            actual,
+            detailed_errors,
+        )
+    };
 
     let validations: TokenStream = validators
         .iter()
         .map(|validator| match validator {
             StringValidator::MaxLen(max_len) => {
+                let error = bound_error("TooLong", "max", max_len, quote!(val.len()));
+                let report = report_error(error);
                 quote!(
                     if val.len() > #max_len {
-                        return Err(#error_name::TooLong);
+                        #report
                     }
                 )
             }
             StringValidator::MinLen(min_len) => {
+                let error = bound_error("TooShort", "min", min_len, quote!(val.len()));
+                let report = report_error(error);
                 quote!(
                     if val.len() < #min_len {
-                        return Err(#error_name::TooShort);
+                        #report
+                    }
+                )
+            }
+            StringValidator::CharLenMax(max_len) => {
+                let error = bound_error("TooLongChars", "max", max_len, quote!(val.chars().count()));
+                let report = report_error(error);
+                quote!(
+                    if val.chars().count() > #max_len {
+                        #report
+                    }
+                )
+            }
+            StringValidator::CharLenMin(min_len) => {
+                let error = bound_error("TooShortChars", "min", min_len, quote!(val.chars().count()));
+                let report = report_error(error);
+                quote!(
+                    if val.chars().count() < #min_len {
+                        #report
+                    }
+                )
+            }
+            StringValidator::GraphemeLenMax(max_len) => {
+                let error = bound_error("TooLongGraphemes", "max", max_len, quote!(::unicode_segmentation::UnicodeSegmentation::graphemes(val, true).count()));
+                let report = report_error(error);
+                quote!(
+                    if ::unicode_segmentation::UnicodeSegmentation::graphemes(val, true).count() > #max_len {
+                        #report
+                    }
+                )
+            }
+            StringValidator::GraphemeLenMin(min_len) => {
+                let error = bound_error("TooShortGraphemes", "min", min_len, quote!(::unicode_segmentation::UnicodeSegmentation::graphemes(val, true).count()));
+                let report = report_error(error);
+                quote!(
+                    if ::unicode_segmentation::UnicodeSegmentation::graphemes(val, true).count() < #min_len {
+                        #report
                     }
                 )
             }
             StringValidator::NotEmpty => {
+                let report = report_error(quote!(#error_name::Empty));
                 quote!(
                     if val.is_empty() {
-                        return Err(#error_name::Empty);
+                        #report
                     }
                 )
             }
             StringValidator::With(is_valid_fn) => {
                 let tp = quote!(&str);
                 let is_valid_fn = type_custom_closure(is_valid_fn, tp);
+                gen_custom_validator_check(is_valid_fn, &error_name, error_options, collect)
+            }
+            StringValidator::NamedWith(NamedCustomValidator { name, func }) => {
+                let is_valid_fn = type_custom_closure(func, quote!(&str));
+                gen_named_custom_validator_check(is_valid_fn, &error_name, name, collect)
+            }
+            StringValidator::Regex(_) => {
+                // The regex is defined once per type by `gen_regex_static()`.
+                let report = report_error(quote!(#error_name::RegexMismatch));
                 quote!(
-                    if !(#is_valid_fn)(&val) {
-                        return Err(#error_name::Invalid);
+                    if !__nutype_regex__().is_match(&val) {
+                        #report
                     }
                 )
             }
         })
         .collect();
 
-    quote!(
-        fn validate(val: &str) -> ::core::result::Result<(), #error_name> {
-            #validations
-            Ok(())
-        }
+    gen_validate_fn_from_checks(
+        quote!(val: &str),
+        &Generics::default(),
+        &error_name,
+        validations,
+        collect,
     )
 }
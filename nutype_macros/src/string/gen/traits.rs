@@ -1,19 +1,21 @@
 use std::collections::HashSet;
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use crate::{
     common::{
         gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_dislpay,
-            gen_impl_trait_from, gen_impl_trait_into, gen_impl_trait_serde_deserialize,
-            gen_impl_trait_serde_serialize, gen_impl_trait_try_from, split_into_generatable_traits,
-            GeneratableTrait, GeneratableTraits, GeneratedTraits,
+            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
+            gen_impl_trait_deref, gen_impl_trait_dislpay, gen_impl_trait_from, gen_impl_trait_into,
+            gen_impl_trait_schemars_json_schema, gen_impl_trait_serde_deserialize,
+            gen_impl_trait_serde_serialize, gen_impl_trait_sqlx_type, gen_impl_trait_try_from,
+            split_into_generatable_traits, GeneratableTrait, GeneratableTraits, GeneratedTraits,
         },
         models::TypeName,
     },
-    string::models::StringDeriveTrait,
+    string::models::{StringDeriveTrait, StringGuard, StringValidator},
 };
 
 type StringGeneratableTrait = GeneratableTrait<StringStandardTrait, StringIrregularTrait>;
@@ -36,13 +38,17 @@ enum StringStandardTrait {
 enum StringIrregularTrait {
     FromStr,
     AsRef,
+    Deref,
     Into,
     From,
     TryFrom,
     Borrow,
     Display,
+    Default,
     SerdeSerialize,
     SerdeDeserialize,
+    SchemarsJsonSchema,
+    SqlxType,
 }
 
 impl From<StringDeriveTrait> for StringGeneratableTrait {
@@ -69,6 +75,9 @@ impl From<StringDeriveTrait> for StringGeneratableTrait {
             StringDeriveTrait::AsRef => {
                 StringGeneratableTrait::Irregular(StringIrregularTrait::AsRef)
             }
+            StringDeriveTrait::Deref => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::Deref)
+            }
             StringDeriveTrait::Into => {
                 StringGeneratableTrait::Irregular(StringIrregularTrait::Into)
             }
@@ -81,6 +90,9 @@ impl From<StringDeriveTrait> for StringGeneratableTrait {
             StringDeriveTrait::Borrow => {
                 StringGeneratableTrait::Irregular(StringIrregularTrait::Borrow)
             }
+            StringDeriveTrait::Default => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::Default)
+            }
             StringDeriveTrait::Display => {
                 StringGeneratableTrait::Irregular(StringIrregularTrait::Display)
             }
@@ -90,6 +102,12 @@ impl From<StringDeriveTrait> for StringGeneratableTrait {
             StringDeriveTrait::SerdeDeserialize => {
                 StringGeneratableTrait::Irregular(StringIrregularTrait::SerdeDeserialize)
             }
+            StringDeriveTrait::SchemarsJsonSchema => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::SchemarsJsonSchema)
+            }
+            StringDeriveTrait::SqlxType => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::SqlxType)
+            }
         }
     }
 }
@@ -111,7 +129,9 @@ impl ToTokens for StringStandardTrait {
 
 pub fn gen_traits(
     type_name: &TypeName,
-    maybe_error_type_name: Option<Ident>,
+    maybe_error_type_name: Option<syn::Path>,
+    maybe_default_value: Option<syn::Expr>,
+    schema_refinements: TokenStream,
     traits: HashSet<StringDeriveTrait>,
 ) -> GeneratedTraits {
     let GeneratableTraits {
@@ -125,8 +145,13 @@ pub fn gen_traits(
         )]
     };
 
-    let implement_traits =
-        gen_implemented_traits(type_name, maybe_error_type_name, irregular_traits);
+    let implement_traits = gen_implemented_traits(
+        type_name,
+        maybe_error_type_name,
+        maybe_default_value,
+        schema_refinements,
+        irregular_traits,
+    );
 
     GeneratedTraits {
         derive_standard_traits,
@@ -136,18 +161,22 @@ pub fn gen_traits(
 
 fn gen_implemented_traits(
     type_name: &TypeName,
-    maybe_error_type_name: Option<Ident>,
+    maybe_error_type_name: Option<syn::Path>,
+    maybe_default_value: Option<syn::Expr>,
+    schema_refinements: TokenStream,
     impl_traits: Vec<StringIrregularTrait>,
 ) -> TokenStream {
+    let generics = Generics::default();
     impl_traits
         .iter()
         .map(|t| match t {
-            StringIrregularTrait::AsRef => gen_impl_trait_as_ref(type_name, quote!(str)),
+            StringIrregularTrait::AsRef => gen_impl_trait_as_ref(type_name, &generics, quote!(str)),
+            StringIrregularTrait::Deref => gen_impl_trait_deref(type_name, &generics, quote!(str)),
             StringIrregularTrait::FromStr => {
                 gen_impl_from_str(type_name, maybe_error_type_name.as_ref())
             }
             StringIrregularTrait::From => gen_impl_from_str_and_string(type_name),
-            StringIrregularTrait::Into => gen_impl_trait_into(type_name, quote!(String)),
+            StringIrregularTrait::Into => gen_impl_trait_into(type_name, &generics, quote!(String)),
             StringIrregularTrait::TryFrom => {
                 let error_type_name = maybe_error_type_name
                     .as_ref()
@@ -155,10 +184,36 @@ fn gen_implemented_traits(
                 gen_impl_try_from(type_name, error_type_name)
             }
             StringIrregularTrait::Borrow => gen_impl_borrow_str_and_string(type_name),
-            StringIrregularTrait::Display => gen_impl_trait_dislpay(type_name),
-            StringIrregularTrait::SerdeSerialize => gen_impl_trait_serde_serialize(type_name),
+            StringIrregularTrait::Default => {
+                let default_value = maybe_default_value
+                    .as_ref()
+                    .expect("Default trait is expected to have default value");
+                gen_impl_trait_default(
+                    type_name,
+                    &generics,
+                    default_value,
+                    maybe_error_type_name.is_some(),
+                )
+            }
+            StringIrregularTrait::Display => gen_impl_trait_dislpay(type_name, &generics),
+            StringIrregularTrait::SerdeSerialize => {
+                gen_impl_trait_serde_serialize(type_name, &generics)
+            }
             StringIrregularTrait::SerdeDeserialize => gen_impl_trait_serde_deserialize(
                 type_name,
+                &generics,
+                quote!(String),
+                maybe_error_type_name.as_ref(),
+            ),
+            StringIrregularTrait::SchemarsJsonSchema => gen_impl_trait_schemars_json_schema(
+                type_name,
+                &generics,
+                quote!(String),
+                &schema_refinements,
+            ),
+            StringIrregularTrait::SqlxType => gen_impl_trait_sqlx_type(
+                type_name,
+                &generics,
                 quote!(String),
                 maybe_error_type_name.as_ref(),
             ),
@@ -166,7 +221,10 @@ fn gen_implemented_traits(
         .collect()
 }
 
-fn gen_impl_from_str(type_name: &TypeName, maybe_error_type_name: Option<&Ident>) -> TokenStream {
+fn gen_impl_from_str(
+    type_name: &TypeName,
+    maybe_error_type_name: Option<&syn::Path>,
+) -> TokenStream {
     if let Some(error_type_name) = maybe_error_type_name {
         quote! {
             impl core::str::FromStr for #type_name {
@@ -191,8 +249,9 @@ fn gen_impl_from_str(type_name: &TypeName, maybe_error_type_name: Option<&Ident>
 }
 
 fn gen_impl_from_str_and_string(type_name: &TypeName) -> TokenStream {
-    let impl_from_string = gen_impl_trait_from(type_name, quote!(String));
-    let impl_from_str = gen_impl_trait_from(type_name, quote!(&str));
+    let generics = Generics::default();
+    let impl_from_string = gen_impl_trait_from(type_name, &generics, quote!(String));
+    let impl_from_str = gen_impl_trait_from(type_name, &generics, quote!(&str));
 
     quote! {
         #impl_from_string
@@ -200,9 +259,12 @@ fn gen_impl_from_str_and_string(type_name: &TypeName) -> TokenStream {
     }
 }
 
-fn gen_impl_try_from(type_name: &TypeName, error_type_name: &Ident) -> TokenStream {
-    let impl_try_from_string = gen_impl_trait_try_from(type_name, quote!(String), error_type_name);
-    let impl_try_from_str = gen_impl_trait_try_from(type_name, quote!(&str), error_type_name);
+fn gen_impl_try_from(type_name: &TypeName, error_type_name: &syn::Path) -> TokenStream {
+    let generics = Generics::default();
+    let impl_try_from_string =
+        gen_impl_trait_try_from(type_name, &generics, quote!(String), error_type_name);
+    let impl_try_from_str =
+        gen_impl_trait_try_from(type_name, &generics, quote!(&str), error_type_name);
 
     quote! {
         #impl_try_from_string
@@ -211,11 +273,56 @@ fn gen_impl_try_from(type_name: &TypeName, error_type_name: &Ident) -> TokenStre
 }
 
 fn gen_impl_borrow_str_and_string(type_name: &TypeName) -> TokenStream {
-    let impl_borrow_string = gen_impl_trait_borrow(type_name, quote!(String));
-    let impl_borrow_str = gen_impl_trait_borrow(type_name, quote!(str));
+    let generics = Generics::default();
+    let impl_borrow_string = gen_impl_trait_borrow(type_name, &generics, quote!(String));
+    let impl_borrow_str = gen_impl_trait_borrow(type_name, &generics, quote!(str));
 
     quote! {
         #impl_borrow_string
         #impl_borrow_str
     }
 }
+
+/// Generate statements, that set `minLength`, `maxLength` and `pattern` of the JSON schema according to the validators.
+///
+/// JSON schema counts length in chars. Length limits are set only when they never reject
+/// a valid value: a string has at most as many chars as bytes and at least as many chars as graphemes.
+pub fn gen_json_schema_constraints(guard: &StringGuard) -> TokenStream {
+    let mut min_length: Option<usize> = None;
+    let mut max_length: Option<usize> = None;
+    let mut maybe_pattern: Option<&str> = None;
+
+    for validator in guard.validators() {
+        match validator {
+            StringValidator::NotEmpty => min_length = min_length.max(Some(1)),
+            StringValidator::CharLenMin(len) | StringValidator::GraphemeLenMin(len) => {
+                min_length = min_length.max(Some(*len))
+            }
+            StringValidator::MaxLen(len) | StringValidator::CharLenMax(len) => {
+                max_length = Some(max_length.map_or(*len, |max| max.min(*len)))
+            }
+            StringValidator::Regex(pattern) => maybe_pattern = Some(pattern),
+            StringValidator::MinLen(_)
+            | StringValidator::GraphemeLenMax(_)
+            | StringValidator::With(_)
+            | StringValidator::NamedWith(_) => {}
+        }
+    }
+
+    let to_u32 = |len: usize| u32::try_from(len).unwrap_or(u32::MAX);
+    let maybe_min_length = min_length.map(to_u32).map(|min_length| {
+        quote!(schema_object.string().min_length = ::core::option::Option::Some(#min_length);)
+    });
+    let maybe_max_length = max_length.map(to_u32).map(|max_length| {
+        quote!(schema_object.string().max_length = ::core::option::Option::Some(#max_length);)
+    });
+    let maybe_pattern = maybe_pattern.map(|pattern| {
+        quote!(schema_object.string().pattern = ::core::option::Option::Some(#pattern.to_owned());)
+    });
+
+    quote!(
+        #maybe_min_length
+        #maybe_max_length
+        #maybe_pattern
+    )
+}
@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 
 use crate::{
     common::models::{Guard, RawGuard},
-    common::models::{Kind, SpannedItem},
+    common::models::{Kind, NamedCustomValidator, SpannedItem},
 };
 
 // Sanitizer
@@ -58,8 +58,20 @@ pub type SpannedStringValidator = SpannedItem<StringValidator>;
 pub enum StringValidator {
     MinLen(usize),
     MaxLen(usize),
+    CharLenMin(usize),
+    CharLenMax(usize),
     NotEmpty,
     With(TokenStream),
+    NamedWith(NamedCustomValidator),
+
+    #[cfg_attr(not(feature = "regex"), allow(dead_code))]
+    Regex(String),
+
+    #[cfg_attr(not(feature = "graphemes"), allow(dead_code))]
+    GraphemeLenMin(usize),
+
+    #[cfg_attr(not(feature = "graphemes"), allow(dead_code))]
+    GraphemeLenMax(usize),
 }
 
 impl Kind for StringValidator {
@@ -69,18 +81,32 @@ impl Kind for StringValidator {
         match self {
             Self::MinLen(_) => StringValidatorKind::MinLen,
             Self::MaxLen(_) => StringValidatorKind::MaxLen,
+            Self::CharLenMin(_) => StringValidatorKind::CharLenMin,
+            Self::CharLenMax(_) => StringValidatorKind::CharLenMax,
             Self::NotEmpty => StringValidatorKind::NotEmpty,
             Self::With(_) => StringValidatorKind::With,
+            Self::NamedWith(validator) => {
+                StringValidatorKind::NamedWith(validator.name.to_string())
+            }
+            Self::Regex(_) => StringValidatorKind::Regex,
+            Self::GraphemeLenMin(_) => StringValidatorKind::GraphemeLenMin,
+            Self::GraphemeLenMax(_) => StringValidatorKind::GraphemeLenMax,
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum StringValidatorKind {
     MinLen,
     MaxLen,
+    CharLenMin,
+    CharLenMax,
     NotEmpty,
     With,
+    NamedWith(String),
+    Regex,
+    GraphemeLenMin,
+    GraphemeLenMax,
 }
 
 impl std::fmt::Display for StringValidatorKind {
@@ -88,8 +114,14 @@ impl std::fmt::Display for StringValidatorKind {
         match self {
             Self::MinLen => write!(f, "min_len"),
             Self::MaxLen => write!(f, "max_len"),
+            Self::CharLenMin => write!(f, "char_len_min"),
+            Self::CharLenMax => write!(f, "char_len_max"),
             Self::NotEmpty => write!(f, "not_empty"),
             Self::With => write!(f, "with"),
+            Self::NamedWith(name) => write!(f, "{name}"),
+            Self::Regex => write!(f, "regex"),
+            Self::GraphemeLenMin => write!(f, "grapheme_len_min"),
+            Self::GraphemeLenMax => write!(f, "grapheme_len_max"),
         }
     }
 }
@@ -107,16 +139,20 @@ pub enum StringDeriveTrait {
     Ord,
     FromStr,
     AsRef,
+    Deref,
     From,
     TryFrom,
     Into,
     Hash,
     Borrow,
     Display,
+    Default,
     // // External crates
     //
     SerdeSerialize,
     SerdeDeserialize,
+    SchemarsJsonSchema,
+    SqlxType,
     // Arbitrary,
 }
 
@@ -1,20 +1,28 @@
+use crate::common::models::Attributes;
 use crate::common::parse::{
-    is_comma, parse_nutype_attributes, parse_value_as_number, parse_with_token_stream,
-    split_and_parse,
+    is_comma, parse_named_custom_validator, parse_nutype_attributes, parse_value_as_number,
+    parse_with_token_stream, split_and_parse,
 };
+use crate::common::validate::validate_error_options;
 use crate::string::models::StringGuard;
 use crate::string::models::StringRawGuard;
 use crate::string::models::{StringSanitizer, StringValidator};
-use proc_macro2::{Span, TokenStream, TokenTree};
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
 
 use super::models::{SpannedStringSanitizer, SpannedStringValidator};
-use super::validate::validate_string_meta;
+use super::validate::{validate_string_default_value, validate_string_meta};
 
-pub fn parse_attributes(input: TokenStream) -> Result<StringGuard, syn::Error> {
-    parse_raw_attributes(input).and_then(validate_string_meta)
+pub fn parse_attributes(input: TokenStream) -> Result<Attributes<StringGuard>, syn::Error> {
+    let raw_attributes = parse_raw_attributes(input)?;
+    validate_error_options(&raw_attributes, |v| {
+        matches!(v.item, StringValidator::With(_))
+    })?;
+    let attributes = raw_attributes.try_map_guard(validate_string_meta)?;
+    validate_string_default_value(&attributes)?;
+    Ok(attributes)
 }
 
-fn parse_raw_attributes(input: TokenStream) -> Result<StringRawGuard, syn::Error> {
+fn parse_raw_attributes(input: TokenStream) -> Result<Attributes<StringRawGuard>, syn::Error> {
     parse_nutype_attributes(parse_sanitize_attrs, parse_validate_attrs)(input)
 }
 
@@ -82,6 +90,43 @@ fn parse_validate_attr(tokens: Vec<TokenTree>) -> Result<SpannedStringValidator,
                 };
                 Ok(parsed_validator)
             }
+            "char_len_max" => {
+                let (value, _iter) = parse_value_as_number(token_iter)?;
+                let validator = StringValidator::CharLenMax(value);
+                let parsed_validator = SpannedStringValidator {
+                    item: validator,
+                    span: ident.span(),
+                };
+                Ok(parsed_validator)
+            }
+            "char_len_min" => {
+                let (value, _iter) = parse_value_as_number(token_iter)?;
+                let validator = StringValidator::CharLenMin(value);
+                let parsed_validator = SpannedStringValidator {
+                    item: validator,
+                    span: ident.span(),
+                };
+                Ok(parsed_validator)
+            }
+            "grapheme_len_max" | "grapheme_len_min" => {
+                #[cfg(not(feature = "graphemes"))]
+                return Err(syn::Error::new(ident.span(), format!("To use `{ident}`, the feature `graphemes` of the crate `nutype` needs to be enabled.")));
+
+                #[cfg(feature = "graphemes")]
+                {
+                    let (value, _iter) = parse_value_as_number(token_iter)?;
+                    let validator = if ident == "grapheme_len_max" {
+                        StringValidator::GraphemeLenMax(value)
+                    } else {
+                        StringValidator::GraphemeLenMin(value)
+                    };
+                    let parsed_validator = SpannedStringValidator {
+                        item: validator,
+                        span: ident.span(),
+                    };
+                    Ok(parsed_validator)
+                }
+            }
             "not_empty" => {
                 let validator = StringValidator::NotEmpty;
                 let parsed_validator = SpannedStringValidator {
@@ -92,14 +137,39 @@ fn parse_validate_attr(tokens: Vec<TokenTree>) -> Result<SpannedStringValidator,
             }
             "with" => {
                 let rest_tokens: Vec<_> = token_iter.collect();
-                let stream = parse_with_token_stream(rest_tokens.iter(), ident.span())?;
-                let validator = StringValidator::With(stream);
+                let validator = match rest_tokens.as_slice() {
+                    [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+                        let named = parse_named_custom_validator(group, ident.span())?;
+                        StringValidator::NamedWith(named)
+                    }
+                    _ => {
+                        let stream = parse_with_token_stream(rest_tokens.iter(), ident.span())?;
+                        StringValidator::With(stream)
+                    }
+                };
+
                 let parsed_validator = SpannedStringValidator {
                     item: validator,
                     span: ident.span(),
                 };
                 Ok(parsed_validator)
             }
+            "regex" => {
+                #[cfg(not(feature = "regex"))]
+                return Err(syn::Error::new(ident.span(), "To validate with regex, the feature `regex` of the crate `nutype` needs to be enabled."));
+
+                #[cfg(feature = "regex")]
+                {
+                    let rest_tokens: Vec<_> = token_iter.collect();
+                    let pattern = parse_regex_pattern(rest_tokens, ident.span())?;
+                    let validator = StringValidator::Regex(pattern);
+                    let parsed_validator = SpannedStringValidator {
+                        item: validator,
+                        span: ident.span(),
+                    };
+                    Ok(parsed_validator)
+                }
+            }
             validator => {
                 let msg = format!("Unknown validation rule `{validator}`");
                 let error = syn::Error::new(ident.span(), msg);
@@ -110,3 +180,39 @@ fn parse_validate_attr(tokens: Vec<TokenTree>) -> Result<SpannedStringValidator,
         Err(syn::Error::new(Span::call_site(), "Invalid syntax."))
     }
 }
+
+// Context:
+//   regex = "^[a-z]+$"
+// Input:
+//   = "^[a-z]+$"
+// Output:
+//   ^[a-z]+$
+//
+// The pattern is compiled at expansion time, so a syntax error is reported right away
+// instead of the first time a value gets validated.
+#[cfg(feature = "regex")]
+fn parse_regex_pattern(tokens: Vec<TokenTree>, regex_span: Span) -> Result<String, syn::Error> {
+    use crate::common::parse::is_eq;
+
+    let syntax_error = || {
+        let msg = "Invalid syntax for `regex`. Expected a string literal, e.g.:\n\n    regex = \"^[0-9]{3}$\"\n\n";
+        syn::Error::new(regex_span, msg)
+    };
+
+    let mut token_iter = tokens.into_iter();
+    match token_iter.next() {
+        Some(ref eq_t) if is_eq(eq_t) => {}
+        _ => return Err(syntax_error()),
+    }
+
+    let rest: TokenStream = token_iter.collect();
+    let lit: syn::LitStr = syn::parse2(rest).map_err(|_| syntax_error())?;
+
+    let pattern = lit.value();
+    if let Err(err) = regex::Regex::new(&pattern) {
+        let msg = format!("Invalid regex `{pattern}`:\n{err}");
+        return Err(syn::Error::new(lit.span(), msg));
+    }
+
+    Ok(pattern)
+}
@@ -3,10 +3,15 @@ use std::collections::HashSet;
 use proc_macro2::Span;
 
 use crate::common::models::Kind;
-use crate::common::models::{DeriveTrait, NormalDeriveTrait, SpannedDeriveTrait};
-use crate::common::validate::validate_duplicates;
+use crate::common::models::{
+    Attributes, DeriveTrait, NamedCustomValidator, NormalDeriveTrait, SpannedDeriveTrait,
+};
+use crate::common::validate::{
+    gen_default_value_error, validate_duplicates, validate_error_variant_names,
+};
 use crate::string::models::{StringGuard, StringRawGuard, StringSanitizer, StringValidator};
 
+use super::gen::error::error_variant_name;
 use super::models::{
     SpannedStringSanitizer, SpannedStringValidator, StringDeriveTrait, StringSanitizerKind,
 };
@@ -30,6 +35,45 @@ pub fn validate_string_meta(raw_meta: StringRawGuard) -> Result<StringGuard, syn
     }
 }
 
+/// Verifies at compile time that a string literal default value (e.g. `default = "Anonymous"`)
+/// satisfies the length validators. Sanitizers may change the value, so if there are any,
+/// the check is left to `default()`, that panics on an invalid value.
+pub fn validate_string_default_value(
+    attributes: &Attributes<StringGuard>,
+) -> Result<(), syn::Error> {
+    let (default_value, validators) = match (&attributes.default, &attributes.guard) {
+        (
+            Some(default_value),
+            StringGuard::WithValidation {
+                sanitizers,
+                validators,
+            },
+        ) if sanitizers.is_empty() => (default_value, validators),
+        _ => return Ok(()),
+    };
+    let value = match default_value {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit_str),
+            ..
+        }) => lit_str.value(),
+        _ => return Ok(()),
+    };
+    for validator in validators {
+        let is_violated = match validator {
+            StringValidator::MinLen(min_len) => value.len() < *min_len,
+            StringValidator::MaxLen(max_len) => value.len() > *max_len,
+            StringValidator::CharLenMin(min) => value.chars().count() < *min,
+            StringValidator::CharLenMax(max) => value.chars().count() > *max,
+            StringValidator::NotEmpty => value.is_empty(),
+            _ => false,
+        };
+        if is_violated {
+            return Err(gen_default_value_error(default_value, validator));
+        }
+    }
+    Ok(())
+}
+
 fn validate_validators(
     validators: Vec<SpannedStringValidator>,
 ) -> Result<Vec<StringValidator>, syn::Error> {
@@ -38,36 +82,76 @@ fn validate_validators(
         format!("Duplicated validators `{kind}`.\nDon't worry, you still remain ingenious!")
     })?;
 
+    validate_error_variant_names(&validators, error_variant_name, |v| match v {
+        StringValidator::NamedWith(NamedCustomValidator { name, .. }) => Some(name),
+        _ => None,
+    })?;
+
     // max_len VS min_len
-    let maybe_min_len = validators
-        .iter()
-        .flat_map(|v| match v.item {
-            StringValidator::MinLen(len) => Some((v.span, len)),
+    validate_min_vs_max(
+        &validators,
+        |v| match v {
+            StringValidator::MinLen(len) => Some(*len),
             _ => None,
-        })
-        .next();
-    let maybe_max_len = validators
-        .iter()
-        .flat_map(|v| match v.item {
-            StringValidator::MaxLen(len) => Some((v.span, len)),
+        },
+        |v| match v {
+            StringValidator::MaxLen(len) => Some(*len),
             _ => None,
-        })
-        .next();
-    if let (Some((_min_len_span, min_len)), Some((max_len_span, max_len))) =
-        (maybe_min_len, maybe_max_len)
-    {
-        if min_len > max_len {
-            let msg = "min_len cannot be greater than max_len.\nDon't you find this obvious?";
-            let span = max_len_span;
-            let err = syn::Error::new(span, msg);
-            return Err(err);
-        }
-    }
+        },
+        "min_len cannot be greater than max_len.\nDon't you find this obvious?",
+    )?;
+
+    // char_len_max VS char_len_min
+    validate_min_vs_max(
+        &validators,
+        |v| match v {
+            StringValidator::CharLenMin(len) => Some(*len),
+            _ => None,
+        },
+        |v| match v {
+            StringValidator::CharLenMax(len) => Some(*len),
+            _ => None,
+        },
+        "char_len_min cannot be greater than char_len_max.\nDon't you find this obvious?",
+    )?;
+
+    // grapheme_len_max VS grapheme_len_min
+    validate_min_vs_max(
+        &validators,
+        |v| match v {
+            StringValidator::GraphemeLenMin(len) => Some(*len),
+            _ => None,
+        },
+        |v| match v {
+            StringValidator::GraphemeLenMax(len) => Some(*len),
+            _ => None,
+        },
+        "grapheme_len_min cannot be greater than grapheme_len_max.\nDon't you find this obvious?",
+    )?;
 
     let validators: Vec<StringValidator> = validators.into_iter().map(|v| v.item).collect();
     Ok(validators)
 }
 
+fn validate_min_vs_max(
+    validators: &[SpannedStringValidator],
+    get_min: impl Fn(&StringValidator) -> Option<usize>,
+    get_max: impl Fn(&StringValidator) -> Option<usize>,
+    msg: &str,
+) -> Result<(), syn::Error> {
+    let maybe_min = validators.iter().find_map(|v| get_min(&v.item));
+    let maybe_max = validators
+        .iter()
+        .find_map(|v| get_max(&v.item).map(|max| (v.span, max)));
+    if let (Some(min), Some((max_span, max))) = (maybe_min, maybe_max) {
+        if min > max {
+            let err = syn::Error::new(max_span, msg);
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
 fn validate_sanitizers(
     sanitizers: Vec<SpannedStringSanitizer>,
 ) -> Result<Vec<StringSanitizer>, syn::Error> {
@@ -146,6 +230,7 @@ fn to_string_derive_trait(
     match tr {
         NormalDeriveTrait::Debug => Ok(StringDeriveTrait::Debug),
         NormalDeriveTrait::Display => Ok(StringDeriveTrait::Display),
+        NormalDeriveTrait::Default => Ok(StringDeriveTrait::Default),
         NormalDeriveTrait::Clone => Ok(StringDeriveTrait::Clone),
         NormalDeriveTrait::PartialEq => Ok(StringDeriveTrait::PartialEq),
         NormalDeriveTrait::Eq => Ok(StringDeriveTrait::Eq),
@@ -153,11 +238,23 @@ fn to_string_derive_trait(
         NormalDeriveTrait::Ord => Ok(StringDeriveTrait::Ord),
         NormalDeriveTrait::FromStr => Ok(StringDeriveTrait::FromStr),
         NormalDeriveTrait::AsRef => Ok(StringDeriveTrait::AsRef),
+        NormalDeriveTrait::Deref => Ok(StringDeriveTrait::Deref),
         NormalDeriveTrait::Hash => Ok(StringDeriveTrait::Hash),
         NormalDeriveTrait::Borrow => Ok(StringDeriveTrait::Borrow),
         NormalDeriveTrait::Into => Ok(StringDeriveTrait::Into),
         NormalDeriveTrait::SerdeSerialize => Ok(StringDeriveTrait::SerdeSerialize),
         NormalDeriveTrait::SerdeDeserialize => Ok(StringDeriveTrait::SerdeDeserialize),
+        NormalDeriveTrait::SchemarsJsonSchema => Ok(StringDeriveTrait::SchemarsJsonSchema),
+        NormalDeriveTrait::SqlxType => Ok(StringDeriveTrait::SqlxType),
+        NormalDeriveTrait::Add
+        | NormalDeriveTrait::Sub
+        | NormalDeriveTrait::Mul
+        | NormalDeriveTrait::Div
+        | NormalDeriveTrait::Neg
+        | NormalDeriveTrait::Sum => Err(syn::Error::new(
+            span,
+            format!("{tr:?} trait can be derived only for integer and float based types"),
+        )),
         NormalDeriveTrait::Copy => Err(syn::Error::new(
             span,
             "Copy trait cannot be derived for a String based type",
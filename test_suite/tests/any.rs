@@ -0,0 +1,517 @@
+use nutype::nutype;
+use std::net::Ipv4Addr;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[cfg(test)]
+mod sanitizers {
+    use super::*;
+
+    #[test]
+    fn test_without_sanitizers() {
+        #[nutype]
+        pub struct Location(Point);
+
+        let location = Location::new(Point { x: 1, y: 2 });
+        assert_eq!(location.into_inner(), Point { x: 1, y: 2 });
+    }
+
+    #[test]
+    fn test_with_closure() {
+        #[nutype(sanitize(with = |mut tags: Vec<String>| { tags.sort(); tags.dedup(); tags }))]
+        pub struct Tags(Vec<String>);
+
+        let tags = Tags::new(vec!["b".to_string(), "a".to_string(), "b".to_string()]);
+        assert_eq!(tags.into_inner(), vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn test_closure_with_no_type() {
+        #[nutype(sanitize(with = |p| Point { x: p.x.abs(), y: p.y.abs() }))]
+        pub struct Location(Point);
+
+        let location = Location::new(Point { x: -1, y: 2 });
+        assert_eq!(location.into_inner(), Point { x: 1, y: 2 });
+    }
+
+    fn normalize(path: PathBuf) -> PathBuf {
+        path.components().collect()
+    }
+
+    #[test]
+    fn test_with_function() {
+        #[nutype(sanitize(with = normalize))]
+        pub struct ConfigPath(PathBuf);
+
+        let path = ConfigPath::new(PathBuf::from("/etc//nutype/./config"));
+        assert_eq!(path.into_inner(), PathBuf::from("/etc/nutype/config"));
+    }
+}
+
+#[cfg(test)]
+mod validators {
+    use super::*;
+
+    #[test]
+    fn test_with_closure() {
+        #[nutype(validate(with = |p| p.x >= 0 && p.y >= 0))]
+        #[derive(Debug, PartialEq)]
+        pub struct Location(Point);
+
+        assert_eq!(
+            Location::new(Point { x: -1, y: 0 }),
+            Err(LocationError::Invalid)
+        );
+        assert_eq!(
+            Location::new(Point { x: 1, y: 0 }).unwrap().into_inner(),
+            Point { x: 1, y: 0 }
+        );
+    }
+
+    fn is_private(addr: &Ipv4Addr) -> bool {
+        addr.is_private()
+    }
+
+    #[test]
+    fn test_with_function() {
+        #[nutype(validate(with = is_private))]
+        #[derive(Debug, PartialEq)]
+        pub struct PrivateAddr(Ipv4Addr);
+
+        assert_eq!(
+            PrivateAddr::new(Ipv4Addr::new(8, 8, 8, 8)),
+            Err(PrivateAddrError::Invalid)
+        );
+        assert!(PrivateAddr::new(Ipv4Addr::new(192, 168, 0, 1)).is_ok());
+    }
+
+    #[test]
+    fn test_sanitize_and_validate() {
+        #[nutype(
+            sanitize(with = |tags: Vec<String>| tags.into_iter().filter(|t| !t.is_empty()).collect())
+            validate(with = |tags: &Vec<String>| !tags.is_empty())
+        )]
+        #[derive(Debug, PartialEq)]
+        pub struct Tags(Vec<String>);
+
+        assert_eq!(Tags::new(vec![String::new()]), Err(TagsError::Invalid));
+        assert_eq!(
+            Tags::new(vec![String::new(), "rust".to_string()])
+                .unwrap()
+                .into_inner(),
+            vec!["rust".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_named_validators_and_collect_errors() {
+        #[nutype(
+            collect_errors
+            validate(
+                with(name = Empty, fn = |tags| !tags.is_empty()),
+                with(name = TooMany, fn = |tags| tags.len() <= 2, message = "at most 2 tags"),
+                with(name = Blank, fn = |tags| tags.iter().all(|t| !t.is_empty())),
+            )
+        )]
+        #[derive(Debug, PartialEq)]
+        pub struct Tags(Vec<String>);
+
+        assert_eq!(Tags::new(vec![]), Err(TagsError::Empty));
+        assert_eq!(
+            Tags::new_collecting(vec![String::new(), String::new(), String::new()]),
+            Err(vec![TagsError::TooMany, TagsError::Blank])
+        );
+        assert_eq!(
+            Tags::new(vec![String::new(); 3]).unwrap_err().to_string(),
+            "at most 2 tags"
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct OutOfBounds;
+
+    fn check_bounds(p: &Point) -> Result<(), OutOfBounds> {
+        if p.x.abs() <= 10 && p.y.abs() <= 10 {
+            Ok(())
+        } else {
+            Err(OutOfBounds)
+        }
+    }
+
+    #[test]
+    fn test_custom_error() {
+        #[nutype(error = OutOfBounds, validate(with = check_bounds))]
+        #[derive(Debug, PartialEq)]
+        pub struct Location(Point);
+
+        assert_eq!(Location::new(Point { x: 11, y: 0 }), Err(OutOfBounds));
+        assert!(Location::new(Point { x: 10, y: 0 }).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod types {
+    use super::*;
+
+    #[test]
+    fn test_generic_std_type() {
+        #[nutype(validate(with = |bytes| bytes.len() == 4))]
+        #[derive(Debug)]
+        pub struct Magic(Vec<u8>);
+
+        assert!(Magic::new(vec![1, 2, 3]).is_err());
+        assert_eq!(
+            Magic::new(vec![1, 2, 3, 4]).unwrap().into_inner(),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_array() {
+        #[nutype(validate(with = |bytes| bytes.iter().any(|b| *b != 0)))]
+        #[derive(Debug)]
+        pub struct Key([u8; 4]);
+
+        assert!(Key::new([0; 4]).is_err());
+        assert_eq!(Key::new([0, 0, 0, 1]).unwrap().into_inner(), [0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_path() {
+        #[nutype]
+        pub struct Addr(std::net::Ipv4Addr);
+
+        assert_eq!(
+            Addr::new(Ipv4Addr::LOCALHOST).into_inner(),
+            Ipv4Addr::LOCALHOST
+        );
+    }
+}
+
+#[cfg(test)]
+mod generics {
+    use super::*;
+    use std::borrow::Cow;
+    use test_suite::test_helpers::traits::*;
+
+    #[test]
+    fn test_generic_parameter() {
+        #[nutype(validate(with = |items| !items.is_empty()))]
+        #[derive(Debug, Clone, PartialEq, AsRef, TryFrom, Into)]
+        pub struct NonEmpty<T: Clone>(Vec<T>);
+
+        assert_eq!(NonEmpty::<i32>::new(vec![]), Err(NonEmptyError::Invalid));
+
+        let numbers = NonEmpty::new(vec![1, 2, 3]).unwrap();
+        assert_eq!(numbers.as_ref(), &vec![1, 2, 3]);
+        let numbers: Vec<i32> = numbers.into();
+        assert_eq!(numbers, vec![1, 2, 3]);
+
+        let names = NonEmpty::try_from(vec!["a"]).unwrap();
+        assert_eq!(names.into_inner(), vec!["a"]);
+        should_implement_clone::<NonEmpty<String>>();
+    }
+
+    #[test]
+    fn test_where_clause() {
+        #[nutype(
+            sanitize(with = |mut items: Vec<T>| { items.sort(); items })
+            validate(with(name = HasDuplicates, fn = |items| items.windows(2).all(|w| w[0] != w[1])))
+        )]
+        #[derive(Debug, PartialEq)]
+        pub struct SortedSet<T>(Vec<T>)
+        where
+            T: Ord + std::fmt::Debug;
+
+        assert_eq!(
+            SortedSet::new(vec![3, 1, 2]).unwrap().into_inner(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            SortedSet::new(vec![2, 1, 2]),
+            Err(SortedSetError::HasDuplicates)
+        );
+    }
+
+    #[test]
+    fn test_lifetime() {
+        #[nutype(validate(with = |name| !name.trim().is_empty()))]
+        #[derive(Debug, Display, Borrow)]
+        pub struct Name<'a>(Cow<'a, str>);
+
+        let name = Name::new(Cow::Borrowed("Anna")).unwrap();
+        assert_eq!(name.to_string(), "Anna");
+        should_implement_borrow::<Name<'static>, Cow<'static, str>>();
+        assert!(Name::new(Cow::Owned(" ".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_const_generic() {
+        #[nutype(validate(with = |bytes| bytes.iter().any(|b| *b != 0)))]
+        #[derive(Debug)]
+        pub struct Key<const N: usize>([u8; N]);
+
+        assert!(Key::new([0u8; 8]).is_err());
+        assert_eq!(Key::new([1u8; 2]).unwrap().into_inner(), [1, 1]);
+    }
+
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn test_trait_serde() {
+        #[nutype(validate(with = |items| !items.is_empty()))]
+        #[derive(Debug, Serialize, Deserialize)]
+        pub struct NonEmpty<T>(Vec<T>);
+
+        let items: NonEmpty<u8> = serde_json::from_str("[1, 2]").unwrap();
+        assert_eq!(serde_json::to_string(&items).unwrap(), "[1,2]");
+        assert!(serde_json::from_str::<NonEmpty<u8>>("[]").is_err());
+    }
+}
+
+#[cfg(test)]
+mod traits {
+    use super::*;
+    use test_suite::test_helpers::traits::*;
+
+    #[test]
+    fn test_without_validation() {
+        #[nutype]
+        #[derive(
+            Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, From, Into, AsRef, Borrow,
+        )]
+        pub struct Location(Point);
+
+        should_implement_debug::<Location>();
+        should_implement_clone::<Location>();
+        should_implement_copy::<Location>();
+        should_implement_hash::<Location>();
+        should_implement_from::<Location, Point>();
+        should_implement_borrow::<Location, Point>();
+
+        let location = Location::from(Point { x: 1, y: 2 });
+        assert_eq!(location.as_ref(), &Point { x: 1, y: 2 });
+        let point: Point = location.into();
+        assert_eq!(point, Point { x: 1, y: 2 });
+    }
+
+    #[test]
+    fn test_with_validation() {
+        #[nutype(validate(with = |addr| !addr.is_unspecified()))]
+        #[derive(Debug, Clone, PartialEq, TryFrom, FromStr, Display)]
+        pub struct Addr(Ipv4Addr);
+
+        should_implement_try_from::<Addr, Ipv4Addr>();
+        should_implement_from_str::<Addr>();
+
+        let addr: Addr = "127.0.0.1".parse().unwrap();
+        assert_eq!(addr.to_string(), "127.0.0.1");
+        assert!("0.0.0.0".parse::<Addr>().is_err());
+        assert!("localhost".parse::<Addr>().is_err());
+    }
+
+    #[test]
+    fn test_trait_deref() {
+        #[nutype(validate(with = |tags| !tags.is_empty()))]
+        #[derive(Deref)]
+        pub struct Tags(Vec<String>);
+
+        let tags = Tags::new(vec!["a".to_string(), "b".to_string()]).unwrap();
+        assert_eq!(tags.len(), 2);
+        assert!(tags.contains(&"b".to_string()));
+    }
+
+    #[test]
+    fn test_trait_deref_for_generic_type() {
+        #[nutype]
+        #[derive(Deref)]
+        pub struct Items<T>(Vec<T>);
+
+        let items = Items::new(vec![1, 2, 3]);
+        assert_eq!(items.iter().sum::<i32>(), 6);
+    }
+
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn test_trait_serde() {
+        #[nutype(validate(with = |tags| !tags.is_empty()))]
+        #[derive(Debug, Serialize, Deserialize)]
+        pub struct Tags(Vec<String>);
+
+        let tags = Tags::new(vec!["a".to_string()]).unwrap();
+        let json = serde_json::to_string(&tags).unwrap();
+        assert_eq!(json, r#"["a"]"#);
+
+        let tags: Tags = serde_json::from_str(r#"["b"]"#).unwrap();
+        assert_eq!(tags.into_inner(), vec!["b".to_string()]);
+        assert!(serde_json::from_str::<Tags>("[]").is_err());
+    }
+
+    #[test]
+    fn test_trait_default() {
+        #[nutype(
+            validate(with = |p| p.x >= 0 && p.y >= 0)
+            default = Point { x: 1, y: 1 }
+        )]
+        #[derive(Debug, Default)]
+        pub struct Location(Point);
+
+        assert_eq!(Location::default().into_inner(), Point { x: 1, y: 1 });
+    }
+
+    #[test]
+    fn test_trait_default_for_generic_type() {
+        #[nutype(default = Vec::new())]
+        #[derive(Default)]
+        pub struct Items<T>(Vec<T>);
+
+        assert!(Items::<u8>::default().into_inner().is_empty());
+    }
+
+    #[cfg(feature = "schemars08")]
+    #[test]
+    fn test_trait_json_schema() {
+        /// Non-empty list of items.
+        #[nutype(validate(with = |items| !items.is_empty()))]
+        #[derive(JsonSchema)]
+        pub struct Items<T>(Vec<T>);
+
+        let schema = serde_json::to_value(schemars::schema_for!(Items<u8>)).unwrap();
+        assert_eq!(schema["description"], "Non-empty list of items.");
+        assert_eq!(schema["type"], "array");
+        assert_eq!(schema["items"]["format"], "uint8");
+    }
+
+    #[cfg(feature = "sqlx08")]
+    #[tokio::test]
+    async fn test_trait_sqlx_type() {
+        #[nutype(validate(with = |bytes| bytes.len() == 2))]
+        #[derive(Debug, SqlxType)]
+        pub struct Pair(Vec<u8>);
+
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+        let pair: Pair = sqlx::query_scalar("SELECT ?")
+            .bind(Pair::new(vec![1, 2]).unwrap())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(pair.into_inner(), vec![1, 2]);
+
+        let err = sqlx::query_scalar::<_, Pair>("SELECT x'010203'")
+            .fetch_one(&pool)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Failed to decode Pair: invalid"));
+    }
+}
+
+#[cfg(test)]
+mod sanitize_and_validate {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_and_validate_with_generics() {
+        #[nutype(
+            sanitize(with = |mut v: Vec<T>| { v.sort(); v })
+            validate(with = |v| !v.is_empty())
+        )]
+        #[derive(Debug)]
+        pub struct SortedNonEmpty<T: Ord>(Vec<T>);
+
+        assert_eq!(SortedNonEmpty::sanitize(vec![3, 1, 2]), vec![1, 2, 3]);
+        assert!(SortedNonEmpty::is_valid(vec![1]));
+        assert_eq!(
+            SortedNonEmpty::<i32>::validate(vec![]),
+            Err(SortedNonEmptyError::Invalid)
+        );
+        assert_eq!(
+            SortedNonEmpty::new(vec![2, 1]).unwrap().into_inner(),
+            vec![1, 2]
+        );
+    }
+}
+
+#[cfg(test)]
+mod nutype_trait {
+    use super::*;
+    use nutype::Nutype;
+
+    #[test]
+    fn test_nutype_trait_with_generics() {
+        #[nutype(validate(with = |v| !v.is_empty()))]
+        #[derive(Debug)]
+        pub struct NonEmpty<T>(Vec<T>);
+
+        fn first<T: Clone>(list: &NonEmpty<T>) -> T {
+            list.as_inner()[0].clone()
+        }
+
+        let list = <NonEmpty<u8> as Nutype>::new(vec![7, 8]).unwrap();
+        assert_eq!(first(&list), 7);
+        assert!(<NonEmpty<u8> as Nutype>::new(vec![]).is_err());
+    }
+}
+
+#[cfg(test)]
+mod new_unchecked {
+    use super::*;
+
+    #[test]
+    fn test_new_unchecked_with_generics() {
+        #[nutype(new_unchecked validate(with = |v| !v.is_empty()))]
+        #[derive(Debug)]
+        pub struct NonEmpty<T>(Vec<T>);
+
+        let list = unsafe { NonEmpty::new_unchecked(vec![1, 2, 3]) };
+        assert_eq!(list.into_inner(), vec![1, 2, 3]);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "`Location::new_unchecked()` received an invalid value.")]
+    fn test_new_unchecked_validates_in_debug_builds() {
+        #[nutype(new_unchecked validate(with = |p| p.is_absolute()))]
+        pub struct Location(PathBuf);
+
+        let location = unsafe { Location::new_unchecked(PathBuf::from("relative/path")) };
+        location.into_inner();
+    }
+}
+
+#[cfg(test)]
+mod constraints {
+    use super::*;
+    use nutype::constraints::{Rule, Value};
+
+    fn is_private(ip: &Ipv4Addr) -> bool {
+        ip.is_private()
+    }
+
+    #[test]
+    fn test_constraints() {
+        #[nutype(validate(with = |ip| !ip.is_loopback(), with(name = NotPrivate, fn = is_private)))]
+        pub struct PrivateIp(Ipv4Addr);
+
+        let constraints = PrivateIp::CONSTRAINTS;
+        assert_eq!(constraints.inner_type, "Ipv4Addr");
+        assert!(constraints.sanitizers.is_empty());
+        assert_eq!(
+            constraints.validators,
+            &[
+                Rule {
+                    name: "with",
+                    args: &[]
+                },
+                Rule {
+                    name: "with",
+                    args: &[Value::Str("NotPrivate")]
+                },
+            ]
+        );
+    }
+}
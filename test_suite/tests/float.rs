@@ -46,6 +46,136 @@ mod sanitizers {
 
         assert_eq!(Age::from(17.0).into_inner(), 17.0);
     }
+
+    #[test]
+    fn test_clamp() {
+        #[nutype(sanitize(clamp(-40.0, 85.0)))]
+        pub struct Temperature(f32);
+
+        assert_eq!(Temperature::new(-273.15).into_inner(), -40.0);
+        assert_eq!(Temperature::new(21.5).into_inner(), 21.5);
+        assert_eq!(Temperature::new(f32::INFINITY).into_inner(), 85.0);
+    }
+
+    #[test]
+    fn test_clamp_with_validation() {
+        #[nutype(
+            sanitize(clamp(0.0, 1.0))
+            validate(min = 0.0, max = 1.0, not_nan)
+        )]
+        #[derive(Debug)]
+        pub struct Humidity(f64);
+
+        assert_eq!(Humidity::new(1.5).unwrap().into_inner(), 1.0);
+        assert!(Humidity::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_round() {
+        #[nutype(sanitize(round(2)))]
+        pub struct Price(f64);
+
+        assert_eq!(Price::new(9.999).into_inner(), 10.0);
+        assert_eq!(Price::new(1.234).into_inner(), 1.23);
+        assert_eq!(Price::new(-1.235).into_inner(), -1.24);
+    }
+
+    #[test]
+    fn test_round_to_integer() {
+        #[nutype(sanitize(round))]
+        pub struct Score(f32);
+
+        assert_eq!(Score::new(2.5).into_inner(), 3.0);
+        assert_eq!(Score::new(-2.4).into_inner(), -2.0);
+    }
+
+    #[test]
+    fn test_round_big_values() {
+        #[nutype(sanitize(round(2)) validate(finite))]
+        #[derive(Debug)]
+        pub struct Price(f64);
+
+        // Scaling these values by 100 overflows, so they're kept as they are.
+        assert_eq!(Price::new(1e307).unwrap().into_inner(), 1e307);
+        assert_eq!(Price::new(f64::MAX).unwrap().into_inner(), f64::MAX);
+        assert_eq!(Price::new(-f64::MAX).unwrap().into_inner(), -f64::MAX);
+    }
+
+    #[test]
+    fn test_round_max_decimals() {
+        #[nutype(sanitize(round(38)))]
+        pub struct Ratio(f32);
+
+        #[nutype(sanitize(round(308)))]
+        pub struct Precise(f64);
+
+        assert_eq!(Ratio::new(1.5).into_inner(), 1.5);
+        assert_eq!(Precise::new(1.5).into_inner(), 1.5);
+    }
+
+    #[test]
+    fn test_floor_ceil_trunc() {
+        #[nutype(sanitize(floor))]
+        pub struct Floor(f64);
+
+        #[nutype(sanitize(ceil))]
+        pub struct Ceil(f64);
+
+        #[nutype(sanitize(trunc))]
+        pub struct Trunc(f64);
+
+        assert_eq!(Floor::new(-1.5).into_inner(), -2.0);
+        assert_eq!(Ceil::new(-1.5).into_inner(), -1.0);
+        assert_eq!(Trunc::new(-1.5).into_inner(), -1.0);
+    }
+
+    #[test]
+    fn test_abs() {
+        #[nutype(sanitize(abs))]
+        pub struct Distance(f32);
+
+        assert_eq!(Distance::new(-3.5).into_inner(), 3.5);
+    }
+
+    #[test]
+    fn test_replace_nan() {
+        #[nutype(
+            sanitize(replace_nan(0.0))
+            validate(not_nan)
+        )]
+        #[derive(Debug, PartialEq, Eq)]
+        pub struct Balance(f64);
+
+        assert_eq!(Balance::new(f64::NAN).unwrap().into_inner(), 0.0);
+        assert_eq!(Balance::new(2.5).unwrap().into_inner(), 2.5);
+    }
+
+    #[test]
+    fn test_sanitizers_are_applied_in_order() {
+        #[nutype(
+            sanitize(abs, round(1), clamp(0.0, 100.0))
+            validate(min = 0.0, max = 100.0)
+        )]
+        #[derive(Debug, FromStr)]
+        pub struct Percentage(f64);
+
+        assert_eq!(Percentage::new(-12.34).unwrap().into_inner(), 12.3);
+        assert_eq!(Percentage::new(-200.0).unwrap().into_inner(), 100.0);
+
+        let percentage: Percentage = "-0.06".parse().unwrap();
+        assert_eq!(percentage.into_inner(), 0.1);
+    }
+
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn test_round_on_deserialize() {
+        #[nutype(sanitize(round(2)))]
+        #[derive(Deserialize)]
+        pub struct Price(f64);
+
+        let price: Price = serde_json::from_str("19.999").unwrap();
+        assert_eq!(price.into_inner(), 20.0);
+    }
 }
 
 #[cfg(test)]
@@ -83,6 +213,47 @@ mod validators {
         assert_eq!(Age::new(25.0).unwrap().into_inner(), 25.0);
     }
 
+    #[test]
+    fn test_finite() {
+        #[nutype(validate(finite))]
+        #[derive(*)]
+        struct Distance(f64);
+
+        assert_eq!(Distance::new(f64::NAN), Err(DistanceError::NotFinite));
+        assert_eq!(Distance::new(f64::INFINITY), Err(DistanceError::NotFinite));
+        assert_eq!(
+            Distance::new(f64::NEG_INFINITY),
+            Err(DistanceError::NotFinite)
+        );
+        assert_eq!(Distance::new(12.5).unwrap().into_inner(), 12.5);
+    }
+
+    #[test]
+    fn test_finite_with_min_and_max() {
+        #[nutype(validate(finite, min = 0.0, max = 1.0))]
+        #[derive(*)]
+        struct Ratio(f32);
+
+        assert_eq!(Ratio::new(f32::NAN), Err(RatioError::NotFinite));
+        assert_eq!(Ratio::new(-0.5), Err(RatioError::TooSmall));
+        assert_eq!(Ratio::new(1.5), Err(RatioError::TooBig));
+        assert_eq!(Ratio::new(0.5).unwrap().into_inner(), 0.5);
+    }
+
+    #[test]
+    fn test_not_nan() {
+        #[nutype(validate(not_nan))]
+        #[derive(*)]
+        struct Temperature(f64);
+
+        assert_eq!(Temperature::new(f64::NAN), Err(TemperatureError::NaN));
+        assert_eq!(
+            Temperature::new(f64::INFINITY).unwrap().into_inner(),
+            f64::INFINITY
+        );
+        assert_eq!(Temperature::new(-273.15).unwrap().into_inner(), -273.15);
+    }
+
     #[cfg(test)]
     mod with {
         use super::*;
@@ -152,6 +323,260 @@ mod validators {
 
             assert_eq!(err.to_string(), "too small");
         }
+
+        #[test]
+        fn test_finite_and_not_nan_error_display() {
+            #[nutype(validate(finite))]
+            #[derive(*)]
+            struct Speed(f64);
+
+            #[nutype(validate(not_nan))]
+            #[derive(*)]
+            struct Balance(f64);
+
+            let err = Speed::try_from(f64::INFINITY).unwrap_err();
+            assert_eq!(err.to_string(), "not finite");
+
+            let err = Balance::try_from(f64::NAN).unwrap_err();
+            assert_eq!(err.to_string(), "NaN");
+        }
+    }
+
+    #[test]
+    fn test_greater_and_less() {
+        #[nutype(validate(greater = 0.0, less = 1.0))]
+        #[derive(Debug, PartialEq)]
+        pub struct Probability(f64);
+
+        assert_eq!(Probability::new(0.0), Err(ProbabilityError::TooSmall));
+        assert_eq!(Probability::new(1.0), Err(ProbabilityError::TooBig));
+        assert_eq!(Probability::new(0.5).unwrap().into_inner(), 0.5);
+        assert_eq!(Probability::new(1.0).unwrap_err().to_string(), "too big");
+    }
+
+    #[test]
+    fn test_greater_and_less_with_detailed_errors() {
+        #[nutype(detailed_errors validate(greater = 0.0, max = 10.0))]
+        #[derive(Debug, PartialEq)]
+        pub struct Weight(f32);
+
+        assert_eq!(
+            Weight::new(0.0),
+            Err(WeightError::TooSmall {
+                greater: 0.0,
+                actual: 0.0
+            })
+        );
+        assert_eq!(
+            Weight::new(-1.0).unwrap_err().to_string(),
+            "too small: expected greater than 0, got -1"
+        );
+        assert!(Weight::new(10.0).is_ok());
+    }
+
+    #[test]
+    fn test_multiple_of() {
+        #[nutype(validate(min = 0.0, multiple_of = 0.05))]
+        #[derive(Debug, PartialEq)]
+        pub struct Price(f64);
+
+        // Values, that can't be represented precisely, are accepted
+        for val in [0.0, 0.05, 0.15, 0.35, 1.1, 19.95, 1_000_000.05] {
+            assert_eq!(Price::new(val).unwrap().into_inner(), val);
+        }
+        assert_eq!(Price::new(0.03), Err(PriceError::NotMultipleOf));
+        assert_eq!(Price::new(19.99), Err(PriceError::NotMultipleOf));
+        assert_eq!(Price::new(0.03).unwrap_err().to_string(), "not a multiple");
+    }
+
+    #[test]
+    fn test_multiple_of_rejects_non_finite_values() {
+        #[nutype(validate(multiple_of = 0.05))]
+        #[derive(Debug, PartialEq)]
+        pub struct Price(f64);
+
+        assert_eq!(Price::new(f64::INFINITY), Err(PriceError::NotMultipleOf));
+        assert_eq!(
+            Price::new(f64::NEG_INFINITY),
+            Err(PriceError::NotMultipleOf)
+        );
+        assert_eq!(Price::new(f64::NAN), Err(PriceError::NotMultipleOf));
+    }
+
+    #[test]
+    fn test_multiple_of_with_epsilon() {
+        #[nutype(detailed_errors validate(multiple_of(0.5, epsilon = 0.01)))]
+        #[derive(Debug, PartialEq)]
+        pub struct Rating(f32);
+
+        assert!(Rating::new(4.5).is_ok());
+        assert!(Rating::new(4.509).is_ok());
+        assert!(Rating::new(-1.995).is_ok());
+        assert_eq!(
+            Rating::new(4.6),
+            Err(RatingError::NotMultipleOf {
+                multiple_of: 0.5,
+                actual: 4.6
+            })
+        );
+        assert_eq!(
+            Rating::new(4.25).unwrap_err().to_string(),
+            "not a multiple: expected a multiple of 0.5, got 4.25"
+        );
+    }
+
+    #[test]
+    fn test_multiple_of_with_epsilon_and_message() {
+        #[nutype(validate(multiple_of(0.25, epsilon = 0.001, message = "use quarters")))]
+        #[derive(Debug, PartialEq)]
+        pub struct Hours(f64);
+
+        assert!(Hours::new(1.75).is_ok());
+        assert_eq!(Hours::new(1.7).unwrap_err().to_string(), "use quarters");
+    }
+
+    mod detailed_errors {
+        use super::*;
+
+        #[test]
+        fn test_min_and_max() {
+            #[nutype(detailed_errors validate(finite, min = 0.0, max = 100.0))]
+            #[derive(Debug, PartialEq)]
+            struct Percentage(f64);
+
+            assert_eq!(
+                Percentage::new(-0.5),
+                Err(PercentageError::TooSmall {
+                    min: 0.0,
+                    actual: -0.5
+                })
+            );
+            assert_eq!(
+                Percentage::new(100.5),
+                Err(PercentageError::TooBig {
+                    max: 100.0,
+                    actual: 100.5
+                })
+            );
+            assert_eq!(Percentage::new(f64::NAN), Err(PercentageError::NotFinite));
+        }
+
+        #[test]
+        fn test_error_display() {
+            #[nutype(detailed_errors validate(min = 0.5, max = 2.5))]
+            #[derive(Debug)]
+            struct Scale(f32);
+
+            let err = Scale::new(0.25).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "too small: expected at least 0.5, got 0.25"
+            );
+
+            let err = Scale::new(3.0).unwrap_err();
+            assert_eq!(err.to_string(), "too big: expected at most 2.5, got 3");
+        }
+    }
+
+    mod collect_errors {
+        use super::*;
+
+        #[test]
+        fn test_new_collecting() {
+            #[nutype(collect_errors validate(finite, min = 0.0))]
+            #[derive(Debug, PartialEq)]
+            struct Distance(f64);
+
+            assert_eq!(
+                Distance::new_collecting(f64::NEG_INFINITY),
+                Err(vec![DistanceError::NotFinite, DistanceError::TooSmall])
+            );
+            assert_eq!(
+                Distance::new_collecting(f64::NAN),
+                Err(vec![DistanceError::NotFinite])
+            );
+            assert_eq!(Distance::new_collecting(1.5).unwrap().into_inner(), 1.5);
+        }
+    }
+
+    mod custom_error {
+        use super::*;
+
+        #[derive(Debug, PartialEq)]
+        pub enum ProbabilityError {
+            OutOfRange,
+        }
+
+        fn validate_probability(p: &f64) -> Result<(), ProbabilityError> {
+            if (0.0..=1.0).contains(p) {
+                Ok(())
+            } else {
+                Err(ProbabilityError::OutOfRange)
+            }
+        }
+
+        #[test]
+        fn test_custom_error() {
+            #[nutype(error = ProbabilityError, validate(with = validate_probability))]
+            #[derive(Debug, PartialEq)]
+            struct Probability(f64);
+
+            assert_eq!(Probability::new(1.5), Err(ProbabilityError::OutOfRange));
+            assert_eq!(
+                Probability::new(f64::NAN),
+                Err(ProbabilityError::OutOfRange)
+            );
+            assert_eq!(Probability::new(0.5).unwrap().into_inner(), 0.5);
+        }
+    }
+
+    mod custom_messages {
+        use super::*;
+
+        #[test]
+        fn test_error_display() {
+            #[nutype(validate(
+                finite(message = "price must be a finite number"),
+                min(0.0, message = "price can not be negative"),
+            ))]
+            #[derive(Debug)]
+            struct Price(f64);
+
+            assert_eq!(
+                Price::new(f64::NAN).unwrap_err().to_string(),
+                "price must be a finite number"
+            );
+            assert_eq!(
+                Price::new(-1.0).unwrap_err().to_string(),
+                "price can not be negative"
+            );
+        }
+    }
+
+    mod named_validators {
+        use super::*;
+
+        #[test]
+        fn test_named_validators() {
+            #[nutype(validate(
+                finite,
+                with(name = NotZero, fn = |x| *x != 0.0, message = "must not be zero"),
+                with(name = Positive, fn = |x| *x > 0.0),
+            ))]
+            #[derive(Debug, PartialEq)]
+            pub struct Divisor(f64);
+
+            assert_eq!(Divisor::new(f64::NAN), Err(DivisorError::NotFinite));
+            assert_eq!(Divisor::new(0.0), Err(DivisorError::NotZero));
+            assert_eq!(Divisor::new(-1.5), Err(DivisorError::Positive));
+            assert_eq!(Divisor::new(2.5).unwrap().into_inner(), 2.5);
+
+            assert_eq!(
+                Divisor::new(0.0).unwrap_err().to_string(),
+                "must not be zero"
+            );
+            assert_eq!(Divisor::new(-1.5).unwrap_err().to_string(), "positive");
+        }
     }
 }
 
@@ -284,6 +709,17 @@ mod traits {
         assert_eq!(weight_ref, &72.650);
     }
 
+    #[test]
+    fn test_trait_deref() {
+        #[nutype]
+        #[derive(Deref)]
+        pub struct Weight(f64);
+
+        let weight = Weight::new(-72.5);
+        assert_eq!(*weight, -72.5);
+        assert_eq!(weight.abs(), 72.5);
+    }
+
     #[test]
     fn test_trait_borrow() {
         use std::borrow::Borrow;
@@ -357,6 +793,66 @@ mod traits {
         assert_eq!(size.to_string(), "35.7");
     }
 
+    #[test]
+    fn test_trait_eq_and_ord_with_finite() {
+        use std::collections::BTreeMap;
+
+        #[nutype(validate(finite))]
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct Price(f64);
+
+        let cheap = Price::new(0.99).unwrap();
+        let expensive = Price::new(100.0).unwrap();
+        assert_eq!(cheap.cmp(&expensive), std::cmp::Ordering::Less);
+        assert_eq!(cheap.cmp(&cheap), std::cmp::Ordering::Equal);
+        assert_eq!(
+            Price::new(-1.0).unwrap().max(Price::new(-2.0).unwrap()),
+            Price::new(-1.0).unwrap()
+        );
+
+        let mut prices = BTreeMap::new();
+        prices.insert(expensive, "caviar");
+        prices.insert(cheap, "bread");
+        let names: Vec<&str> = prices.values().copied().collect();
+        assert_eq!(names, vec!["bread", "caviar"]);
+    }
+
+    #[test]
+    fn test_trait_hash_with_not_nan() {
+        use std::collections::HashSet;
+
+        #[nutype(validate(not_nan))]
+        #[derive(Debug, PartialEq, Eq, Hash)]
+        pub struct Weight(f32);
+
+        should_implement_hash::<Weight>();
+
+        let mut weights = HashSet::new();
+        weights.insert(Weight::new(1.5).unwrap());
+        weights.insert(Weight::new(1.5).unwrap());
+        weights.insert(Weight::new(f32::INFINITY).unwrap());
+        assert_eq!(weights.len(), 2);
+
+        // 0.0 and -0.0 are equal, so they must be treated as the same value
+        weights.insert(Weight::new(0.0).unwrap());
+        weights.insert(Weight::new(-0.0).unwrap());
+        assert_eq!(weights.len(), 3);
+    }
+
+    #[test]
+    fn test_asterisk_with_finite() {
+        #[nutype(validate(finite))]
+        #[derive(*)]
+        pub struct Ratio(f64);
+
+        should_implement_hash::<Ratio>();
+        assert!(Ratio::new(1.0).unwrap() < Ratio::new(2.0).unwrap());
+        assert_eq!(
+            Ratio::new(3.0).unwrap().cmp(&Ratio::new(2.0).unwrap()),
+            std::cmp::Ordering::Greater
+        );
+    }
+
     #[cfg(feature = "serde1")]
     #[test]
     fn test_trait_serialize() {
@@ -409,4 +905,217 @@ mod traits {
             assert_eq!(offset.into_inner(), 13.3);
         }
     }
+
+    #[test]
+    fn test_trait_default_without_validation() {
+        #[nutype(default = -0.5)]
+        #[derive(Default)]
+        pub struct Offset(f64);
+
+        assert_eq!(Offset::default().into_inner(), -0.5);
+    }
+
+    #[test]
+    fn test_trait_default_with_validation() {
+        #[nutype(validate(min = 0.0, max = 1.0), default = 0.5)]
+        #[derive(Debug, Default)]
+        pub struct Ratio(f32);
+
+        assert_eq!(Ratio::default().into_inner(), 0.5);
+    }
+
+    #[test]
+    fn test_trait_default_with_multiple_of() {
+        // 0.3 can't be represented precisely, but it's still a multiple of 0.1.
+        #[nutype(validate(multiple_of = 0.1), default = 0.3)]
+        #[derive(Debug, Default)]
+        pub struct Weight(f32);
+
+        assert_eq!(Weight::default().into_inner(), 0.3);
+    }
+
+    #[test]
+    fn test_trait_default_with_multiple_of_f64() {
+        #[nutype(validate(multiple_of = 0.1), default = 0.3)]
+        #[derive(Debug, Default)]
+        pub struct Weight(f64);
+
+        assert_eq!(Weight::default().into_inner(), 0.3);
+        // Within the tolerance of `f32`, but not of `f64`, so such a default is rejected
+        // at compile time as well (see UI tests).
+        assert!(Weight::new(0.30000001).is_err());
+    }
+
+    #[test]
+    fn test_trait_arithmetic_without_validation() {
+        #[nutype]
+        #[derive(Debug, PartialEq, Add, Sub, Mul, Div, Neg, Sum)]
+        pub struct Offset(f64);
+
+        assert_eq!(Offset::new(1.5) + Offset::new(2.0), Offset::new(3.5));
+        assert_eq!(Offset::new(1.5) - Offset::new(2.0), Offset::new(-0.5));
+        assert_eq!(Offset::new(1.5) * Offset::new(2.0), Offset::new(3.0));
+        assert_eq!(Offset::new(1.5) / Offset::new(2.0), Offset::new(0.75));
+        assert_eq!(-Offset::new(1.5), Offset::new(-1.5));
+
+        let sum: Offset = vec![Offset::new(0.5), Offset::new(0.25)].into_iter().sum();
+        assert_eq!(sum, Offset::new(0.75));
+    }
+
+    #[test]
+    fn test_trait_arithmetic_with_validation() {
+        #[nutype(validate(min = 0.0, max = 1.0))]
+        #[derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul, Div)]
+        pub struct Ratio(f32);
+
+        let ratio = |value| Ratio::new(value).unwrap();
+
+        assert_eq!(ratio(0.25) + ratio(0.5), Ok(ratio(0.75)));
+        assert_eq!(ratio(0.75) + ratio(0.5), Err(RatioError::TooBig));
+        assert_eq!(ratio(0.25) - ratio(0.5), Err(RatioError::TooSmall));
+        assert_eq!(ratio(0.5) / ratio(0.25), Err(RatioError::TooBig));
+
+        assert_eq!(ratio(0.5).checked_mul(ratio(0.5)), Some(ratio(0.25)));
+        assert_eq!(ratio(0.5).checked_div(ratio(0.25)), None);
+
+        assert_eq!(ratio(0.75).saturating_add(ratio(0.5)), ratio(1.0));
+        assert_eq!(ratio(0.25).saturating_sub(ratio(0.5)), ratio(0.0));
+    }
+
+    #[test]
+    fn test_trait_saturating_with_finite() {
+        #[nutype(validate(finite))]
+        #[derive(Debug, PartialEq, Mul)]
+        pub struct Distance(f64);
+
+        let max = Distance::new(f64::MAX).unwrap();
+        let two = Distance::new(2.0).unwrap();
+        assert_eq!(max.saturating_mul(two), Distance::new(f64::MAX).unwrap());
+    }
+
+    #[test]
+    fn test_trait_division_of_zero_by_zero() {
+        #[nutype(validate(finite))]
+        #[derive(Debug, Clone, Copy, PartialEq, Div)]
+        pub struct Distance(f64);
+
+        // `saturating_div` is not generated, since NaN can't be clamped into a finite value.
+        let zero = Distance::new(0.0).unwrap();
+        assert_eq!(zero / zero, Err(DistanceError::NotFinite));
+        assert_eq!(zero.checked_div(zero), None);
+
+        #[nutype(validate(min = 0.0, max = 1.0))]
+        #[derive(Debug, Clone, Copy, Div)]
+        pub struct Ratio(f64);
+
+        // NaN doesn't violate `min` and `max`, so it's as valid as it's for `new()`.
+        let zero = Ratio::new(0.0).unwrap();
+        let nan = zero.saturating_div(zero);
+        assert!(nan.into_inner().is_nan());
+        assert!(Ratio::is_valid(f64::NAN));
+    }
+
+    #[cfg(feature = "schemars08")]
+    #[test]
+    fn test_trait_json_schema() {
+        #[nutype(validate(finite, greater = 0.0, max = 1.5))]
+        #[derive(JsonSchema)]
+        pub struct Ratio(f64);
+
+        let schema = serde_json::to_value(schemars::schema_for!(Ratio)).unwrap();
+        assert_eq!(
+            schema,
+            serde_json::json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "title": "Ratio",
+                "type": "number",
+                "format": "double",
+                "exclusiveMinimum": 0.0,
+                "maximum": 1.5,
+            })
+        );
+    }
+
+    #[cfg(feature = "sqlx08")]
+    #[tokio::test]
+    async fn test_trait_sqlx_type() {
+        #[nutype(sanitize(round(1)))]
+        #[derive(Debug, PartialEq, SqlxType)]
+        pub struct Temperature(f64);
+
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+        let temperature: Temperature = sqlx::query_scalar("SELECT ? + 0.04")
+            .bind(Temperature::new(21.5))
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(temperature, Temperature::new(21.5));
+    }
+}
+
+#[cfg(test)]
+mod new_const {
+    use super::*;
+
+    #[test]
+    fn test_new_const() {
+        #[nutype(validate(greater = 0.0, less = 1.0, finite))]
+        #[derive(Debug)]
+        pub struct Probability(f64);
+
+        const HALF: Probability = Probability::new_const(0.5);
+        assert_eq!(HALF.into_inner(), 0.5);
+    }
+
+    #[test]
+    fn test_new_const_without_validation() {
+        #[nutype]
+        #[derive(Debug)]
+        pub struct Ratio(f32);
+
+        const ONE: Ratio = Ratio::new_const(1.0);
+        assert_eq!(ONE.into_inner(), 1.0);
+    }
+}
+
+#[cfg(test)]
+mod constraints {
+    use super::*;
+    use nutype::constraints::{Rule, Value};
+
+    #[test]
+    fn test_constraints() {
+        #[nutype(sanitize(replace_nan(0.0), round(2)) validate(finite, multiple_of(0.25, epsilon = 0.001)))]
+        pub struct Price(f64);
+
+        let constraints = Price::CONSTRAINTS;
+        assert_eq!(constraints.inner_type, "f64");
+        assert_eq!(
+            constraints.sanitizers,
+            &[
+                Rule {
+                    name: "replace_nan",
+                    args: &[Value::Float(0.0)]
+                },
+                Rule {
+                    name: "round",
+                    args: &[Value::UInt(2)]
+                },
+            ]
+        );
+        assert_eq!(
+            constraints.validators,
+            &[
+                Rule {
+                    name: "finite",
+                    args: &[]
+                },
+                Rule {
+                    name: "multiple_of",
+                    args: &[Value::Float(0.25), Value::Float(0.001)]
+                },
+            ]
+        );
+    }
 }
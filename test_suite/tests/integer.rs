@@ -46,6 +46,38 @@ mod sanitizers {
 
         assert_eq!(Age::from(17).into_inner(), 18);
     }
+
+    #[test]
+    fn test_clamp() {
+        #[nutype(sanitize(clamp(1, 50)))]
+        pub struct PageSize(u32);
+
+        assert_eq!(PageSize::new(0).into_inner(), 1);
+        assert_eq!(PageSize::new(20).into_inner(), 20);
+        assert_eq!(PageSize::new(1_000).into_inner(), 50);
+    }
+
+    #[test]
+    fn test_clamp_with_negative_bounds_and_validation() {
+        #[nutype(
+            sanitize(clamp(-10, 10))
+            validate(min = -10, max = 10)
+        )]
+        #[derive(Debug)]
+        pub struct Offset(i64);
+
+        assert_eq!(Offset::new(-99).unwrap().into_inner(), -10);
+        assert_eq!(Offset::new(99).unwrap().into_inner(), 10);
+    }
+
+    #[test]
+    fn test_clamp_with_other_sanitizers() {
+        #[nutype(sanitize(with = |n| n * 10, clamp(0, 100)))]
+        pub struct Percentage(i32);
+
+        assert_eq!(Percentage::new(5).into_inner(), 50);
+        assert_eq!(Percentage::new(11).into_inner(), 100);
+    }
 }
 
 #[cfg(test)]
@@ -136,6 +168,73 @@ mod validators {
     }
 
     #[cfg(test)]
+    #[test]
+    fn test_greater_and_less() {
+        #[nutype(validate(greater = 0, less = 100))]
+        #[derive(Debug, PartialEq)]
+        pub struct Percentage(i32);
+
+        assert_eq!(Percentage::new(0), Err(PercentageError::TooSmall));
+        assert_eq!(Percentage::new(100), Err(PercentageError::TooBig));
+        assert_eq!(Percentage::new(1).unwrap().into_inner(), 1);
+        assert_eq!(Percentage::new(99).unwrap().into_inner(), 99);
+    }
+
+    #[test]
+    fn test_less_with_detailed_errors_and_message() {
+        #[nutype(detailed_errors validate(min = 1, less(10, message = "must be a single digit")))]
+        #[derive(Debug, PartialEq)]
+        pub struct Digit(u8);
+
+        assert_eq!(
+            Digit::new(10),
+            Err(DigitError::TooBig {
+                less: 10,
+                actual: 10
+            })
+        );
+        assert_eq!(
+            Digit::new(10).unwrap_err().to_string(),
+            "must be a single digit"
+        );
+    }
+
+    #[test]
+    fn test_multiple_of() {
+        #[nutype(validate(min = 6, multiple_of = 6))]
+        #[derive(Debug, PartialEq)]
+        pub struct PackQuantity(u32);
+
+        assert_eq!(PackQuantity::new(6).unwrap().into_inner(), 6);
+        assert_eq!(PackQuantity::new(60).unwrap().into_inner(), 60);
+        assert_eq!(PackQuantity::new(0), Err(PackQuantityError::TooSmall));
+        assert_eq!(PackQuantity::new(7), Err(PackQuantityError::NotMultipleOf));
+        assert_eq!(
+            PackQuantity::new(7).unwrap_err().to_string(),
+            "not a multiple"
+        );
+    }
+
+    #[test]
+    fn test_multiple_of_with_detailed_errors() {
+        #[nutype(detailed_errors validate(multiple_of = 5))]
+        #[derive(Debug, PartialEq)]
+        pub struct Offset(i64);
+
+        assert_eq!(Offset::new(-15).unwrap().into_inner(), -15);
+        assert_eq!(
+            Offset::new(-13),
+            Err(OffsetError::NotMultipleOf {
+                multiple_of: 5,
+                actual: -13
+            })
+        );
+        assert_eq!(
+            Offset::new(12).unwrap_err().to_string(),
+            "not a multiple: expected a multiple of 5, got 12"
+        );
+    }
+
     mod error {
         use super::*;
 
@@ -150,6 +249,134 @@ mod validators {
             assert_eq!(err.to_string(), "too small");
         }
     }
+
+    mod detailed_errors {
+        use super::*;
+
+        #[test]
+        fn test_min_and_max() {
+            #[nutype(detailed_errors validate(min = 18, max = 99))]
+            #[derive(Debug, PartialEq)]
+            struct Age(u8);
+
+            assert_eq!(
+                Age::new(17),
+                Err(AgeError::TooSmall {
+                    min: 18,
+                    actual: 17
+                })
+            );
+            assert_eq!(
+                Age::new(100),
+                Err(AgeError::TooBig {
+                    max: 99,
+                    actual: 100
+                })
+            );
+            assert_eq!(Age::new(18).unwrap().into_inner(), 18);
+        }
+
+        #[test]
+        fn test_error_display() {
+            #[nutype(detailed_errors validate(min = -10, max = 10))]
+            #[derive(Debug)]
+            struct Offset(i32);
+
+            let err = Offset::new(-11).unwrap_err();
+            assert_eq!(err.to_string(), "too small: expected at least -10, got -11");
+
+            let err = Offset::new(12).unwrap_err();
+            assert_eq!(err.to_string(), "too big: expected at most 10, got 12");
+        }
+    }
+
+    mod collect_errors {
+        use super::*;
+
+        #[test]
+        fn test_new_collecting() {
+            #[nutype(collect_errors validate(max = 100, with = |n| n % 2 == 0))]
+            #[derive(Debug, PartialEq)]
+            struct Even(i32);
+
+            assert_eq!(
+                Even::new_collecting(101),
+                Err(vec![EvenError::TooBig, EvenError::Invalid])
+            );
+            assert_eq!(Even::new_collecting(3), Err(vec![EvenError::Invalid]));
+            assert_eq!(Even::new_collecting(4).unwrap().into_inner(), 4);
+            assert_eq!(Even::new(101), Err(EvenError::TooBig));
+        }
+    }
+
+    mod custom_error {
+        use super::*;
+
+        #[derive(Debug, PartialEq)]
+        pub struct OddNumberError(i32);
+
+        impl std::fmt::Display for OddNumberError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{} is odd", self.0)
+            }
+        }
+
+        #[test]
+        fn test_custom_error() {
+            #[nutype(
+                error = OddNumberError
+                validate(with = |n| if n % 2 == 0 { Ok(()) } else { Err(OddNumberError(*n)) })
+            )]
+            #[derive(Debug, PartialEq, TryFrom, FromStr)]
+            struct Even(i32);
+
+            assert_eq!(Even::new(3), Err(OddNumberError(3)));
+            assert_eq!(Even::try_from(4).unwrap().into_inner(), 4);
+
+            let err = "5".parse::<Even>().unwrap_err();
+            assert_eq!(err.to_string(), "Failed to parse Even: 5 is odd");
+        }
+    }
+
+    mod custom_messages {
+        use super::*;
+
+        #[test]
+        fn test_error_display() {
+            #[nutype(validate(min(18, message = "you must be an adult"), max = 150))]
+            #[derive(Debug)]
+            struct Age(u8);
+
+            assert_eq!(
+                Age::new(17).unwrap_err().to_string(),
+                "you must be an adult"
+            );
+            assert_eq!(Age::new(151).unwrap_err().to_string(), "too big");
+        }
+    }
+
+    mod named_validators {
+        use super::*;
+
+        #[test]
+        fn test_named_validators() {
+            #[nutype(validate(
+                min = 1,
+                with(name = Odd, fn = |n| n % 2 == 1),
+                with(name = NotThirteen, fn = |n| *n != 13),
+            ))]
+            #[derive(Debug, PartialEq)]
+            pub struct OddNumber(u32);
+
+            assert_eq!(OddNumber::new(0), Err(OddNumberError::TooSmall));
+            assert_eq!(OddNumber::new(4), Err(OddNumberError::Odd));
+            assert_eq!(OddNumber::new(13), Err(OddNumberError::NotThirteen));
+            assert_eq!(OddNumber::new(7).unwrap().into_inner(), 7);
+
+            assert_eq!(OddNumber::new(4).unwrap_err().to_string(), "odd");
+            assert_eq!(OddNumber::new(13).unwrap_err().to_string(), "not thirteen");
+        }
+    }
 }
 
 #[cfg(test)]
@@ -427,6 +654,18 @@ mod traits {
         assert_eq!(age_ref, &32);
     }
 
+    #[test]
+    fn test_trait_deref() {
+        #[nutype(validate(max = 100))]
+        #[derive(Deref)]
+        pub struct Percentage(u32);
+
+        let percentage = Percentage::new(42).unwrap();
+        assert_eq!(*percentage, 42);
+        assert!(!percentage.is_power_of_two());
+        assert_eq!(percentage.pow(2), 1764);
+    }
+
     #[test]
     fn test_trait_borrow() {
         use std::borrow::Borrow;
@@ -552,4 +791,408 @@ mod traits {
             assert_eq!(offset.into_inner(), 13);
         }
     }
+
+    #[test]
+    fn test_trait_default_without_validation() {
+        #[nutype(default = -1)]
+        #[derive(Default)]
+        pub struct Offset(i32);
+
+        assert_eq!(Offset::default().into_inner(), -1);
+    }
+
+    #[test]
+    fn test_trait_default_with_validation() {
+        #[nutype(default = 18, validate(min = 18, max = 99))]
+        #[derive(Debug, Default)]
+        pub struct Age(u8);
+
+        assert_eq!(Age::default().into_inner(), 18);
+    }
+
+    #[test]
+    fn test_trait_default_with_sanitizers() {
+        #[nutype(
+            sanitize(with = |n| n.clamp(1, 100))
+            validate(min = 1)
+            default = 0
+        )]
+        #[derive(Default)]
+        pub struct Percentage(u32);
+
+        assert_eq!(Percentage::default().into_inner(), 1);
+    }
+
+    const DEFAULT_PORT: u16 = 8080;
+
+    #[test]
+    fn test_trait_default_with_constant() {
+        #[nutype(validate(min = 1024), default = DEFAULT_PORT)]
+        #[derive(Default)]
+        pub struct Port(u16);
+
+        assert_eq!(Port::default().into_inner(), 8080);
+    }
+
+    #[test]
+    #[should_panic(expected = "Default value for type `Port` is invalid")]
+    fn test_trait_default_with_invalid_constant() {
+        #[nutype(validate(min = 10000), default = DEFAULT_PORT)]
+        #[derive(Default)]
+        pub struct Port(u16);
+
+        Port::default();
+    }
+
+    #[test]
+    fn test_trait_add_without_validation() {
+        #[nutype]
+        #[derive(Debug, PartialEq, Add, Sub)]
+        pub struct Offset(i32);
+
+        assert_eq!(Offset::new(3) + Offset::new(4), Offset::new(7));
+        assert_eq!(Offset::new(3) - Offset::new(4), Offset::new(-1));
+        assert_eq!(Offset::new(i32::MAX).checked_add(Offset::new(1)), None);
+        assert_eq!(
+            Offset::new(i32::MAX).saturating_add(Offset::new(1)),
+            Offset::new(i32::MAX)
+        );
+    }
+
+    #[test]
+    fn test_trait_arithmetic_with_validation() {
+        #[nutype(validate(min = 1, max = 100))]
+        #[derive(Debug, Clone, Copy, PartialEq, Add, Sub, Mul, Div)]
+        pub struct Amount(u32);
+
+        let amount = |value| Amount::new(value).unwrap();
+
+        assert_eq!(amount(40) + amount(60), Ok(amount(100)));
+        assert_eq!(amount(50) + amount(51), Err(AmountError::TooBig));
+        assert_eq!(amount(50) - amount(49), Ok(amount(1)));
+        assert_eq!(amount(10) * amount(10), Ok(amount(100)));
+        assert_eq!(amount(10) / amount(20), Err(AmountError::TooSmall));
+
+        assert_eq!(amount(40).checked_add(amount(60)), Some(amount(100)));
+        assert_eq!(amount(50).checked_add(amount(51)), None);
+        assert_eq!(amount(1).checked_sub(amount(2)), None);
+        assert_eq!(amount(100).checked_div(amount(3)), Some(amount(33)));
+
+        assert_eq!(amount(50).saturating_add(amount(51)), amount(100));
+        assert_eq!(amount(1).saturating_sub(amount(2)), amount(1));
+        assert_eq!(amount(20).saturating_mul(amount(20)), amount(100));
+        assert_eq!(amount(10).saturating_div(amount(20)), amount(1));
+    }
+
+    #[test]
+    fn test_trait_neg() {
+        #[nutype(validate(min = -10, max = 5))]
+        #[derive(Debug, PartialEq, Neg)]
+        pub struct Level(i8);
+
+        assert_eq!(-Level::new(-5).unwrap(), Level::new(5));
+        assert_eq!(-Level::new(-6).unwrap(), Err(LevelError::TooBig));
+    }
+
+    #[test]
+    fn test_trait_sum() {
+        #[nutype(validate(max = 10))]
+        #[derive(Debug, PartialEq, Sum)]
+        pub struct Score(u8);
+
+        let scores = |values: Vec<u8>| values.into_iter().map(|v| Score::new(v).unwrap());
+        assert_eq!(
+            scores(vec![1, 2, 3]).sum::<Result<Score, _>>(),
+            Score::new(6)
+        );
+        assert_eq!(
+            scores(vec![5, 6]).sum::<Result<Score, _>>(),
+            Err(ScoreError::TooBig)
+        );
+    }
+
+    #[nutype]
+    #[derive(Debug, PartialEq, Add, Neg, Sum)]
+    pub struct Counter(i8);
+
+    #[test]
+    fn test_trait_arithmetic_at_boundary() {
+        assert_eq!(Counter::new(126) + Counter::new(1), Counter::new(i8::MAX));
+        assert_eq!(-Counter::new(-127), Counter::new(i8::MAX));
+        assert_eq!(
+            [Counter::new(100), Counter::new(27)]
+                .into_iter()
+                .sum::<Counter>(),
+            Counter::new(i8::MAX)
+        );
+    }
+
+    // Overflow panics in release builds as well, instead of wrapping.
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_trait_add_overflow() {
+        let _ = Counter::new(i8::MAX) + Counter::new(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn test_trait_neg_overflow() {
+        let _ = -Counter::new(i8::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_trait_sum_overflow() {
+        let _: Counter = [Counter::new(100), Counter::new(28)].into_iter().sum();
+    }
+
+    #[cfg(feature = "schemars08")]
+    #[test]
+    fn test_trait_json_schema() {
+        /// Age in years.
+        #[nutype(validate(min = 18, less = 150))]
+        #[derive(JsonSchema)]
+        pub struct Age(u8);
+
+        let schema = serde_json::to_value(schemars::schema_for!(Age)).unwrap();
+        assert_eq!(
+            schema,
+            serde_json::json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "title": "Age",
+                "description": "Age in years.",
+                "type": "integer",
+                "format": "uint8",
+                "minimum": 18.0,
+                "exclusiveMaximum": 150.0,
+            })
+        );
+    }
+
+    #[cfg(feature = "schemars08")]
+    #[test]
+    fn test_trait_json_schema_as_field() {
+        #[nutype(validate(max = 100, multiple_of = 5))]
+        #[derive(JsonSchema)]
+        pub struct Percentage(i32);
+
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Discount {
+            percentage: Percentage,
+        }
+
+        let schema = serde_json::to_value(schemars::schema_for!(Discount)).unwrap();
+        assert_eq!(
+            schema["properties"]["percentage"],
+            serde_json::json!({ "$ref": "#/definitions/Percentage" })
+        );
+        assert_eq!(
+            schema["definitions"]["Percentage"],
+            serde_json::json!({
+                "type": "integer",
+                "format": "int32",
+                "maximum": 100.0,
+                "multipleOf": 5.0,
+            })
+        );
+    }
+
+    #[cfg(feature = "sqlx08")]
+    #[tokio::test]
+    async fn test_trait_sqlx_type() {
+        #[nutype(validate(min = 18))]
+        #[derive(Debug, PartialEq, SqlxType)]
+        pub struct Age(i64);
+
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+        let age: Age = sqlx::query_scalar("SELECT ? + 1")
+            .bind(Age::new(20).unwrap())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(age, Age::new(21).unwrap());
+
+        let err = sqlx::query_scalar::<_, Age>("SELECT 17")
+            .fetch_one(&pool)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Failed to decode Age: too small"));
+    }
+}
+
+#[cfg(test)]
+mod new_const {
+    use super::*;
+
+    #[nutype(validate(min = 1024, max = 49151))]
+    #[derive(Debug, PartialEq)]
+    pub struct Port(u16);
+
+    const HTTP_ALT: Port = Port::new_const(8080);
+
+    #[test]
+    fn test_new_const_with_validation() {
+        assert_eq!(HTTP_ALT.into_inner(), 8080);
+        assert_eq!(HTTP_ALT, Port::new(8080).unwrap());
+    }
+
+    #[test]
+    fn test_new_const_without_validation() {
+        #[nutype]
+        #[derive(Debug, PartialEq)]
+        pub struct Offset(i32);
+
+        const ZERO: Offset = Offset::new_const(0);
+        assert_eq!(ZERO.into_inner(), 0);
+    }
+
+    #[test]
+    fn test_new_const_with_multiple_of() {
+        #[nutype(validate(greater = 0, multiple_of = 6))]
+        #[derive(Debug)]
+        pub struct PackQuantity(u32);
+
+        const TWO_PACKS: PackQuantity = PackQuantity::new_const(12);
+        assert_eq!(TWO_PACKS.into_inner(), 12);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`Port::new_const()` received a value, that violates `max` validator."
+    )]
+    fn test_new_const_panics_at_runtime() {
+        let value = std::hint::black_box(50000);
+        Port::new_const(value);
+    }
+}
+
+#[cfg(test)]
+mod sanitize_and_validate {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_and_validate() {
+        #[nutype(sanitize(clamp(0, 200)) validate(multiple_of = 2))]
+        #[derive(Debug, PartialEq)]
+        pub struct Even(i32);
+
+        assert_eq!(Even::sanitize(250), 200);
+        assert_eq!(Even::sanitize(-1), 0);
+        assert_eq!(Even::validate(18), Ok(()));
+        assert_eq!(Even::validate(17), Err(EvenError::NotMultipleOf));
+        assert!(Even::is_valid(42));
+        assert!(!Even::is_valid(1));
+
+        // The value is sanitized first, like in `new()`
+        assert_eq!(Even::validate(201), Ok(()));
+        assert!(Even::is_valid(-1));
+    }
+
+    #[test]
+    fn test_validate_without_validation() {
+        #[nutype]
+        pub struct Count(u64);
+
+        let result: Result<(), std::convert::Infallible> = Count::validate(7);
+        assert_eq!(result, Ok(()));
+        assert_eq!(Count::sanitize(7), 7);
+    }
+}
+
+#[cfg(test)]
+mod nutype_trait {
+    use super::*;
+    use nutype::Nutype;
+
+    fn double<T>(value: T) -> Result<T, T::Error>
+    where
+        T: Nutype<Inner = i32>,
+    {
+        T::new(value.into_inner() * 2)
+    }
+
+    #[test]
+    fn test_nutype_trait() {
+        #[nutype(validate(max = 10))]
+        #[derive(Debug, PartialEq)]
+        pub struct Level(i32);
+
+        assert_eq!(double(Level::new(5).unwrap()), Level::new(10));
+        assert_eq!(double(Level::new(6).unwrap()), Err(LevelError::TooBig));
+        assert_eq!(*Level::new(3).unwrap().as_inner(), 3);
+    }
+}
+
+#[cfg(test)]
+mod new_unchecked {
+    use super::*;
+
+    #[nutype(new_unchecked validate(min = 18, max = 99))]
+    #[derive(Debug)]
+    pub struct Age(u8);
+
+    #[test]
+    fn test_new_unchecked() {
+        let age = unsafe { Age::new_unchecked(42) };
+        assert_eq!(age.into_inner(), 42);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "`Age::new_unchecked()` received an invalid value.")]
+    fn test_new_unchecked_validates_in_debug_builds() {
+        let _ = unsafe { Age::new_unchecked(17) };
+    }
+}
+
+#[cfg(test)]
+mod constraints {
+    use super::*;
+    use nutype::constraints::{Rule, Value};
+
+    #[test]
+    fn test_signed_constraints() {
+        #[nutype(sanitize(clamp(-40, 40)) validate(greater = -50, max = 50))]
+        pub struct Offset(i16);
+
+        let constraints = Offset::CONSTRAINTS;
+        assert_eq!(constraints.inner_type, "i16");
+        assert_eq!(
+            constraints.sanitizers,
+            &[Rule {
+                name: "clamp",
+                args: &[Value::Int(-40), Value::Int(40)]
+            }]
+        );
+        assert_eq!(
+            constraints.validators,
+            &[
+                Rule {
+                    name: "greater",
+                    args: &[Value::Int(-50)]
+                },
+                Rule {
+                    name: "max",
+                    args: &[Value::Int(50)]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsigned_constraints() {
+        #[nutype(validate(min = 18, multiple_of = 2))]
+        pub struct Age(u8);
+
+        assert_eq!(
+            Age::CONSTRAINTS.validator("min").unwrap().args,
+            &[Value::UInt(18)]
+        );
+        assert_eq!(
+            Age::CONSTRAINTS.validator("multiple_of").unwrap().args,
+            &[Value::UInt(2)]
+        );
+    }
 }
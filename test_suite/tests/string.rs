@@ -133,6 +133,72 @@ mod validators {
         assert_eq!(Name::new("Julia").unwrap().into_inner(), "Julia");
     }
 
+    #[test]
+    fn test_char_len_max() {
+        #[nutype(validate(char_len_max = 5))]
+        #[derive(Debug, PartialEq)]
+        pub struct Name(String);
+
+        // 5 chars, but 10 bytes
+        assert_eq!(Name::new("Антон").unwrap().into_inner(), "Антон");
+        assert_eq!(Name::new("Сергій"), Err(NameError::TooLongChars));
+    }
+
+    #[test]
+    fn test_char_len_min() {
+        #[nutype(validate(char_len_min = 6))]
+        #[derive(Debug, PartialEq)]
+        pub struct Name(String);
+
+        assert_eq!(Name::new("Антон"), Err(NameError::TooShortChars));
+        assert_eq!(Name::new("Сергій").unwrap().into_inner(), "Сергій");
+    }
+
+    #[test]
+    fn test_char_len_and_len_together() {
+        #[nutype(validate(char_len_min = 2, char_len_max = 3, max_len = 8))]
+        #[derive(Debug, PartialEq)]
+        pub struct Emoji(String);
+
+        assert_eq!(Emoji::new("🦀"), Err(EmojiError::TooShortChars));
+        assert_eq!(Emoji::new("🦀🦀🦀🦀"), Err(EmojiError::TooLongChars));
+        assert_eq!(Emoji::new("🦀🦀🦀"), Err(EmojiError::TooLong));
+        assert_eq!(Emoji::new("🦀🦀").unwrap().into_inner(), "🦀🦀");
+    }
+
+    #[test]
+    fn test_char_len_error_display() {
+        #[nutype(validate(char_len_min = 2, char_len_max = 3))]
+        #[derive(Debug)]
+        pub struct Code(String);
+
+        assert_eq!(
+            Code::new("x").unwrap_err().to_string(),
+            "too short (in chars)"
+        );
+        assert_eq!(
+            Code::new("wxyz").unwrap_err().to_string(),
+            "too long (in chars)"
+        );
+    }
+
+    #[cfg(feature = "graphemes")]
+    #[test]
+    fn test_grapheme_len() {
+        #[nutype(validate(grapheme_len_min = 2, grapheme_len_max = 3))]
+        #[derive(Debug, PartialEq)]
+        pub struct Flags(String);
+
+        // Every flag is a single grapheme, but consists of 2 chars.
+        assert_eq!(Flags::new("🇺🇦"), Err(FlagsError::TooShortGraphemes));
+        assert_eq!(Flags::new("🇺🇦🇩🇪🇫🇷🇮🇹"), Err(FlagsError::TooLongGraphemes));
+        assert_eq!(Flags::new("🇺🇦🇩🇪").unwrap().into_inner(), "🇺🇦🇩🇪");
+        assert_eq!(
+            Flags::new("🇺").unwrap_err().to_string(),
+            "too short (in graphemes)"
+        );
+    }
+
     #[cfg(test)]
     mod with {
         use super::*;
@@ -181,6 +247,55 @@ mod validators {
         }
     }
 
+    #[cfg(feature = "regex1")]
+    mod regex {
+        use super::*;
+
+        #[test]
+        fn test_regex() {
+            #[nutype(validate(regex = "^[A-Z]{3}-[0-9]{4}$"))]
+            #[derive(Debug, PartialEq)]
+            pub struct TicketKey(String);
+
+            assert_eq!(
+                TicketKey::new("abc-1234"),
+                Err(TicketKeyError::RegexMismatch)
+            );
+            assert_eq!(
+                TicketKey::new("ABC-12345"),
+                Err(TicketKeyError::RegexMismatch)
+            );
+            assert_eq!(TicketKey::new("ABC-1234").unwrap().into_inner(), "ABC-1234");
+        }
+
+        #[test]
+        fn test_raw_string_regex_with_sanitizers() {
+            #[nutype(
+                sanitize(trim, lowercase)
+                validate(not_empty, regex = r"^[a-z0-9]+(-[a-z0-9]+)*$")
+            )]
+            #[derive(Debug, PartialEq)]
+            pub struct Slug(String);
+
+            assert_eq!(Slug::new("  "), Err(SlugError::Empty));
+            assert_eq!(Slug::new("hello world"), Err(SlugError::RegexMismatch));
+            assert_eq!(
+                Slug::new(" Hello-World ").unwrap().into_inner(),
+                "hello-world"
+            );
+        }
+
+        #[test]
+        fn test_error_display() {
+            #[nutype(validate(regex = "^[0-9]+$"))]
+            #[derive(Debug)]
+            pub struct Digits(String);
+
+            let err = Digits::new("12a").unwrap_err();
+            assert_eq!(err.to_string(), "regex mismatch");
+        }
+    }
+
     #[test]
     fn test_try_from_trait() {
         #[nutype(validate(not_empty))]
@@ -209,6 +324,331 @@ mod validators {
 
         assert_eq!(EmailError::Empty.to_string(), "empty");
     }
+
+    mod detailed_errors {
+        use super::*;
+
+        #[test]
+        fn test_len_bounds() {
+            #[nutype(
+                detailed_errors
+                validate(not_empty, min_len = 3, max_len = 8)
+            )]
+            #[derive(Debug, PartialEq)]
+            pub struct Login(String);
+
+            assert_eq!(Login::new(""), Err(LoginError::Empty));
+            assert_eq!(
+                Login::new("ab"),
+                Err(LoginError::TooShort { min: 3, actual: 2 })
+            );
+            assert_eq!(
+                Login::new("administrator"),
+                Err(LoginError::TooLong { max: 8, actual: 13 })
+            );
+            assert!(Login::new("admin").is_ok());
+        }
+
+        #[test]
+        fn test_char_len_bounds() {
+            #[nutype(detailed_errors, validate(char_len_min = 2, char_len_max = 3))]
+            #[derive(Debug, PartialEq)]
+            pub struct Code(String);
+
+            assert_eq!(
+                Code::new("äöüß"),
+                Err(CodeError::TooLongChars { max: 3, actual: 4 })
+            );
+            assert_eq!(
+                Code::new("ä"),
+                Err(CodeError::TooShortChars { min: 2, actual: 1 })
+            );
+        }
+
+        #[test]
+        fn test_error_display() {
+            #[nutype(detailed_errors validate(min_len = 3, max_len = 5, char_len_max = 4))]
+            pub struct Nick(String);
+
+            assert_eq!(
+                NickError::TooShort { min: 3, actual: 1 }.to_string(),
+                "too short: expected at least 3 bytes, got 1"
+            );
+            assert_eq!(
+                NickError::TooLong { max: 5, actual: 7 }.to_string(),
+                "too long: expected at most 5 bytes, got 7"
+            );
+            assert_eq!(
+                NickError::TooLongChars { max: 4, actual: 5 }.to_string(),
+                "too long: expected at most 4 chars, got 5"
+            );
+        }
+    }
+
+    mod collect_errors {
+        use super::*;
+
+        #[test]
+        fn test_new_collecting() {
+            #[nutype(
+                collect_errors
+                validate(not_empty, min_len = 3, max_len = 5, with = |s| s.is_ascii())
+            )]
+            #[derive(Debug, PartialEq)]
+            pub struct Login(String);
+
+            assert_eq!(
+                Login::new_collecting(""),
+                Err(vec![LoginError::Empty, LoginError::TooShort])
+            );
+            assert_eq!(
+                Login::new_collecting("äöü"),
+                Err(vec![LoginError::TooLong, LoginError::Invalid])
+            );
+            assert_eq!(Login::new_collecting("ab"), Err(vec![LoginError::TooShort]));
+            assert_eq!(Login::new_collecting("abc").unwrap().into_inner(), "abc");
+
+            // `new()` still stops at the first error
+            assert_eq!(Login::new(""), Err(LoginError::Empty));
+        }
+
+        #[test]
+        fn test_with_detailed_errors() {
+            #[nutype(
+                collect_errors,
+                detailed_errors,
+                validate(max_len = 4, char_len_max = 2)
+            )]
+            #[derive(Debug, PartialEq)]
+            pub struct Code(String);
+
+            assert_eq!(
+                Code::new_collecting("äöü"),
+                Err(vec![
+                    CodeError::TooLong { max: 4, actual: 6 },
+                    CodeError::TooLongChars { max: 2, actual: 3 },
+                ])
+            );
+        }
+
+        #[cfg(feature = "regex1")]
+        #[test]
+        fn test_with_regex() {
+            #[nutype(collect_errors validate(not_empty, regex = "^[a-z]+$"))]
+            #[derive(Debug, PartialEq)]
+            pub struct Slug(String);
+
+            assert_eq!(
+                Slug::new_collecting(""),
+                Err(vec![SlugError::Empty, SlugError::RegexMismatch])
+            );
+            assert_eq!(Slug::new("Foo"), Err(SlugError::RegexMismatch));
+            assert_eq!(Slug::new_collecting("foo").unwrap().into_inner(), "foo");
+        }
+    }
+
+    mod custom_error {
+        use super::*;
+        use std::fmt;
+
+        mod errors {
+            #[derive(Debug, PartialEq)]
+            pub enum DomainError {
+                InvalidEmail,
+                TooLong(usize),
+            }
+        }
+        use errors::DomainError;
+
+        impl fmt::Display for DomainError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{self:?}")
+            }
+        }
+
+        fn validate_email(email: &str) -> Result<(), DomainError> {
+            if email.contains('@') {
+                Ok(())
+            } else {
+                Err(DomainError::InvalidEmail)
+            }
+        }
+
+        #[test]
+        fn test_with_function() {
+            #[nutype(error = errors::DomainError, validate(with = validate_email))]
+            #[derive(Debug, PartialEq, TryFrom)]
+            pub struct Email(String);
+
+            assert_eq!(Email::new("foo"), Err(DomainError::InvalidEmail));
+            assert_eq!(
+                Email::try_from("foo@bar.com").unwrap().into_inner(),
+                "foo@bar.com"
+            );
+        }
+
+        #[test]
+        fn test_with_closure() {
+            #[nutype(
+                sanitize(trim)
+                error = DomainError
+                validate(with = |s| if s.len() > 5 { Err(DomainError::TooLong(s.len())) } else { Ok(()) })
+            )]
+            #[derive(Debug, PartialEq)]
+            pub struct Code(String);
+
+            assert_eq!(Code::new(" abcdefg "), Err(DomainError::TooLong(7)));
+            assert_eq!(Code::new(" abc ").unwrap().into_inner(), "abc");
+        }
+
+        #[test]
+        fn test_with_collect_errors() {
+            #[nutype(collect_errors error = DomainError validate(with = validate_email))]
+            #[derive(Debug)]
+            pub struct Email(String);
+
+            assert_eq!(
+                Email::new_collecting("foo").unwrap_err(),
+                vec![DomainError::InvalidEmail]
+            );
+        }
+
+        #[cfg(feature = "serde1")]
+        #[test]
+        fn test_deserialize() {
+            #[nutype(error = DomainError validate(with = validate_email))]
+            #[derive(Debug, Deserialize)]
+            pub struct Email(String);
+
+            let err = serde_json::from_str::<Email>("\"foo\"").unwrap_err();
+            assert_eq!(err.to_string(), "InvalidEmail");
+
+            let email: Email = serde_json::from_str("\"foo@bar.com\"").unwrap();
+            assert_eq!(email.into_inner(), "foo@bar.com");
+        }
+    }
+
+    mod custom_messages {
+        use super::*;
+
+        #[test]
+        fn test_error_display() {
+            #[nutype(validate(
+                not_empty(message = "username is required"),
+                max_len(32, message = "username must be at most 32 characters"),
+                min_len = 3,
+                with(|s| s.is_ascii(), message = "username must be ASCII"),
+            ))]
+            #[derive(Debug, PartialEq)]
+            pub struct Username(String);
+
+            assert_eq!(Username::new(""), Err(UsernameError::Empty));
+            assert_eq!(
+                Username::new("").unwrap_err().to_string(),
+                "username is required"
+            );
+            assert_eq!(
+                Username::new("a".repeat(33)).unwrap_err().to_string(),
+                "username must be at most 32 characters"
+            );
+            assert_eq!(Username::new("ab").unwrap_err().to_string(), "too short");
+            assert_eq!(
+                Username::new("äbc").unwrap_err().to_string(),
+                "username must be ASCII"
+            );
+        }
+
+        #[test]
+        fn test_with_detailed_errors() {
+            #[nutype(
+                detailed_errors
+                validate(max_len(3, message = "code is too long"))
+            )]
+            #[derive(Debug, PartialEq)]
+            pub struct Code(String);
+
+            let err = Code::new("abcd").unwrap_err();
+            assert_eq!(err, CodeError::TooLong { max: 3, actual: 4 });
+            assert_eq!(err.to_string(), "code is too long");
+        }
+
+        #[cfg(feature = "serde1")]
+        #[test]
+        fn test_deserialize() {
+            #[nutype(validate(not_empty(message = "name is required")))]
+            #[derive(Debug, Deserialize)]
+            pub struct Name(String);
+
+            let err = serde_json::from_str::<Name>("\"\"").unwrap_err();
+            assert_eq!(err.to_string(), "name is required");
+        }
+    }
+
+    mod named_validators {
+        use super::*;
+
+        fn is_not_reserved(name: &str) -> bool {
+            !["admin", "root"].contains(&name)
+        }
+
+        #[test]
+        fn test_named_validators() {
+            #[nutype(validate(
+                with(name = NotReserved, fn = is_not_reserved),
+                with(name = NoSpaces, fn = |s| !s.contains(' ')),
+            ))]
+            #[derive(Debug, PartialEq)]
+            pub struct Username(String);
+
+            assert_eq!(Username::new("root"), Err(UsernameError::NotReserved));
+            assert_eq!(Username::new("john doe"), Err(UsernameError::NoSpaces));
+            assert_eq!(Username::new("john").unwrap().into_inner(), "john");
+        }
+
+        #[test]
+        fn test_error_display() {
+            #[nutype(validate(
+                with(name = NotReserved, fn = is_not_reserved),
+                with(name = NoSpaces, fn = |s| !s.contains(' '), message = "must not contain spaces"),
+            ))]
+            #[derive(Debug)]
+            pub struct Username(String);
+
+            assert_eq!(
+                Username::new("admin").unwrap_err().to_string(),
+                "not reserved"
+            );
+            assert_eq!(
+                Username::new("a b").unwrap_err().to_string(),
+                "must not contain spaces"
+            );
+        }
+
+        #[test]
+        fn test_with_builtin_validators() {
+            #[nutype(
+                collect_errors
+                validate(
+                    max_len = 5,
+                    with(|s| s.is_ascii()),
+                    with(name = NotReserved, fn = is_not_reserved),
+                )
+            )]
+            #[derive(Debug, PartialEq)]
+            pub struct Username(String);
+
+            assert_eq!(Username::new("root"), Err(UsernameError::NotReserved));
+            assert_eq!(Username::new("äbc"), Err(UsernameError::Invalid));
+            assert_eq!(
+                Username::new_collecting("admin"),
+                Err(vec![UsernameError::NotReserved])
+            );
+            assert_eq!(
+                Username::new_collecting("äääroot"),
+                Err(vec![UsernameError::TooLong, UsernameError::Invalid])
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -327,6 +767,19 @@ mod derives {
         assert_eq!(name_ref, "Anna")
     }
 
+    #[test]
+    fn test_trait_deref() {
+        #[nutype(validate(not_empty))]
+        #[derive(Deref)]
+        pub struct Name(String);
+
+        let name = Name::new("Anna").unwrap();
+        let name_str: &str = &name;
+        assert_eq!(name_str, "Anna");
+        assert_eq!(name.len(), 4);
+        assert!(name.starts_with("An"));
+    }
+
     #[test]
     fn test_trait_borrow_str() {
         use std::borrow::Borrow;
@@ -433,4 +886,311 @@ mod derives {
             assert_eq!(email.into_inner(), "foo@bar.com");
         }
     }
+
+    #[test]
+    fn test_trait_default_without_validation() {
+        #[nutype(default = "Anonymous")]
+        #[derive(Default)]
+        pub struct Name(String);
+
+        assert_eq!(Name::default().into_inner(), "Anonymous");
+    }
+
+    #[test]
+    fn test_trait_default_with_validation() {
+        #[nutype(
+            sanitize(trim, lowercase)
+            validate(not_empty, max_len = 10)
+            default = "  Guest "
+        )]
+        #[derive(Debug, Default)]
+        pub struct Login(String);
+
+        assert_eq!(Login::default().into_inner(), "guest");
+    }
+
+    #[test]
+    fn test_trait_default_with_expression() {
+        #[nutype(
+            validate(not_empty)
+            default = "user".repeat(2)
+        )]
+        #[derive(Default)]
+        pub struct Name(String);
+
+        assert_eq!(Name::default().into_inner(), "useruser");
+    }
+
+    #[test]
+    fn test_trait_default_in_struct() {
+        #[nutype(validate(min_len = 3), default = "Bob")]
+        #[derive(Debug, PartialEq, Default)]
+        pub struct Name(String);
+
+        #[derive(Debug, Default)]
+        struct User {
+            name: Name,
+        }
+
+        assert_eq!(User::default().name.into_inner(), "Bob");
+    }
+
+    #[cfg(all(feature = "schemars08", feature = "regex1"))]
+    #[test]
+    fn test_trait_json_schema() {
+        /// Name of a user.
+        ///
+        /// Must be unique.
+        #[nutype(validate(not_empty, char_len_max = 20, regex = "^[a-z]+$"))]
+        #[derive(JsonSchema)]
+        pub struct Username(String);
+
+        let schema = serde_json::to_value(schemars::schema_for!(Username)).unwrap();
+        assert_eq!(
+            schema,
+            serde_json::json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "title": "Username",
+                "description": "Name of a user.\n\nMust be unique.",
+                "type": "string",
+                "minLength": 1,
+                "maxLength": 20,
+                "pattern": "^[a-z]+$",
+            })
+        );
+    }
+
+    #[cfg(feature = "schemars08")]
+    #[test]
+    fn test_trait_json_schema_length_in_bytes() {
+        // JSON schema counts chars, so only `max_len` can be expressed.
+        #[nutype(validate(min_len = 3, max_len = 255))]
+        #[derive(JsonSchema)]
+        pub struct Email(String);
+
+        let schema = serde_json::to_value(schemars::schema_for!(Email)).unwrap();
+        assert_eq!(schema.get("minLength"), None);
+        assert_eq!(schema["maxLength"], 255);
+    }
+
+    #[cfg(feature = "sqlx08")]
+    #[tokio::test]
+    async fn test_trait_sqlx_type() {
+        #[nutype(sanitize(trim) validate(not_empty))]
+        #[derive(Debug, PartialEq, SqlxType)]
+        pub struct Username(String);
+
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+        let username: Username = sqlx::query_scalar("SELECT ?")
+            .bind(Username::new("alice").unwrap())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(username, Username::new("alice").unwrap());
+
+        // Decoded values are sanitized
+        let username: Username = sqlx::query_scalar("SELECT '  bob  '")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(username.into_inner(), "bob");
+
+        // Decoded values are validated
+        let err = sqlx::query_scalar::<_, Username>("SELECT '   '")
+            .fetch_one(&pool)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Failed to decode Username: empty"));
+    }
+}
+
+#[cfg(test)]
+mod sanitize_and_validate {
+    use super::*;
+
+    #[nutype(sanitize(trim, lowercase) validate(not_empty, max_len = 5))]
+    #[derive(Debug, PartialEq)]
+    pub struct Tag(String);
+
+    #[test]
+    fn test_sanitize() {
+        assert_eq!(Tag::sanitize("  RuSt ".to_string()), "rust");
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(Tag::validate("rust"), Ok(()));
+        assert_eq!(Tag::validate(""), Err(TagError::Empty));
+        assert_eq!(Tag::validate("rustacean"), Err(TagError::TooLong));
+
+        // The value is sanitized first, like in `new()`
+        assert_eq!(Tag::validate("  RUST "), Ok(()));
+        assert_eq!(Tag::validate("   "), Err(TagError::Empty));
+        assert_eq!(Tag::new("   "), Err(TagError::Empty));
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(Tag::is_valid("rust"));
+        assert!(!Tag::is_valid(""));
+
+        // Agrees with `new()`
+        assert!(!Tag::is_valid("   "));
+        assert!(Tag::new("   ").is_err());
+        assert!(Tag::is_valid(" Rust "));
+        assert!(Tag::new(" Rust ").is_ok());
+    }
+
+    #[test]
+    fn test_without_validation() {
+        #[nutype(sanitize(trim))]
+        pub struct Comment(String);
+
+        assert_eq!(Comment::sanitize(" hi ".to_string()), "hi");
+        assert_eq!(Comment::validate(""), Ok(()));
+        assert!(Comment::is_valid(""));
+    }
+}
+
+#[cfg(test)]
+mod nutype_trait {
+    use super::*;
+    use nutype::Nutype;
+
+    #[nutype(sanitize(trim) validate(not_empty))]
+    #[derive(Debug, PartialEq)]
+    pub struct Title(String);
+
+    #[nutype(sanitize(trim))]
+    #[derive(Debug, PartialEq)]
+    pub struct Note(String);
+
+    fn parse_all<T: Nutype>(values: Vec<T::Inner>) -> Result<Vec<T>, T::Error> {
+        values.into_iter().map(T::new).collect()
+    }
+
+    fn inner_len<T: Nutype<Inner = String>>(value: &T) -> usize {
+        value.as_inner().len()
+    }
+
+    #[test]
+    fn test_with_validation() {
+        let titles: Vec<Title> = parse_all(vec![" Dune ".to_string()]).unwrap();
+        assert_eq!(titles, vec![Title::new("Dune").unwrap()]);
+        assert_eq!(inner_len(&titles[0]), 4);
+
+        assert_eq!(
+            parse_all::<Title>(vec!["Dune".to_string(), " ".to_string()]),
+            Err(TitleError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_without_validation() {
+        let notes: Result<Vec<Note>, std::convert::Infallible> =
+            parse_all(vec![" hello ".to_string()]);
+        let note = notes.unwrap().pop().unwrap();
+        assert_eq!(Nutype::into_inner(note), "hello");
+    }
+}
+
+#[cfg(test)]
+mod new_unchecked {
+    use super::*;
+
+    #[nutype(new_unchecked sanitize(trim, lowercase) validate(not_empty, max_len = 20))]
+    #[derive(Debug, PartialEq)]
+    pub struct Email(String);
+
+    #[test]
+    fn test_new_unchecked_skips_sanitization() {
+        let email = unsafe { Email::new_unchecked("  Bob@Example.com ") };
+        assert_eq!(email.into_inner(), "  Bob@Example.com ");
+    }
+
+    #[test]
+    fn test_new_unchecked_with_valid_value() {
+        let email = unsafe { Email::new_unchecked("bob@example.com") };
+        assert_eq!(email, Email::new("bob@example.com").unwrap());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "`Email::new_unchecked()` received an invalid value.")]
+    fn test_new_unchecked_validates_in_debug_builds() {
+        let _ = unsafe { Email::new_unchecked("") };
+    }
+
+    #[test]
+    fn test_new_unchecked_without_validation() {
+        #[nutype(sanitize(trim) new_unchecked)]
+        pub struct Name(String);
+
+        let name = unsafe { Name::new_unchecked(" Tom ") };
+        assert_eq!(name.into_inner(), " Tom ");
+    }
+}
+
+#[cfg(test)]
+mod constraints {
+    use super::*;
+    use nutype::constraints::{Rule, Value};
+
+    #[test]
+    fn test_constraints() {
+        #[nutype(sanitize(trim, lowercase) validate(not_empty, char_len_max = 20))]
+        pub struct Username(String);
+
+        let constraints = Username::CONSTRAINTS;
+        assert_eq!(constraints.inner_type, "String");
+        assert_eq!(
+            constraints.sanitizers,
+            &[
+                Rule {
+                    name: "trim",
+                    args: &[]
+                },
+                Rule {
+                    name: "lowercase",
+                    args: &[]
+                },
+            ]
+        );
+        assert_eq!(
+            constraints.validators,
+            &[
+                Rule {
+                    name: "not_empty",
+                    args: &[]
+                },
+                Rule {
+                    name: "char_len_max",
+                    args: &[Value::UInt(20)]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_constraints_without_validation() {
+        #[nutype]
+        pub struct Comment(String);
+
+        assert!(Comment::CONSTRAINTS.sanitizers.is_empty());
+        assert!(Comment::CONSTRAINTS.validators.is_empty());
+        assert_eq!(Comment::CONSTRAINTS.validator("max_len"), None);
+    }
+
+    #[cfg(feature = "regex1")]
+    #[test]
+    fn test_regex_constraint() {
+        #[nutype(validate(regex = "^[0-9]{3}-[0-9]{3}$"))]
+        pub struct PhoneNumber(String);
+
+        assert_eq!(
+            PhoneNumber::CONSTRAINTS.validator("regex").unwrap().args,
+            &[Value::Str("^[0-9]{3}-[0-9]{3}$")]
+        );
+    }
 }
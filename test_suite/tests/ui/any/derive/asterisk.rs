@@ -0,0 +1,7 @@
+use nutype::nutype;
+
+#[nutype]
+#[derive(*)]
+pub struct Bytes(Vec<u8>);
+
+fn main () {}
@@ -0,0 +1,7 @@
+use nutype::nutype;
+
+#[nutype]
+#[derive(Debug, FromStr)]
+pub struct Items<T>(Vec<T>);
+
+fn main () {}
@@ -0,0 +1,12 @@
+use nutype::nutype;
+
+#[nutype(validate(
+    with = |p: &Point| p.0 >= 0,
+    with(name = Invalid, fn = |p: &Point| p.1 >= 0),
+))]
+pub struct Position(Point);
+
+#[derive(Debug)]
+pub struct Point(i32, i32);
+
+fn main () {}
@@ -0,0 +1,12 @@
+use nutype::nutype;
+
+#[derive(Debug)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[nutype(validate(max = 10))]
+pub struct Location(Point);
+
+fn main () {}
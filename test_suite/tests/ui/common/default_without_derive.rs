@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(default = 18, validate(min = 18))]
+pub struct Age(u8);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use nutype::nutype;
+
+#[nutype(validate(min = 18))]
+#[derive(Default)]
+pub struct Age(u8);
+
+fn main() {}
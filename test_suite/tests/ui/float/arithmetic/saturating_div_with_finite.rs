@@ -0,0 +1,11 @@
+use nutype::nutype;
+
+// `0.0 / 0.0` is NaN, which can't be clamped into a finite value.
+#[nutype(validate(finite))]
+#[derive(Div)]
+pub struct Distance(f64);
+
+fn main() {
+    let zero = Distance::new(0.0).unwrap();
+    let _ = zero.saturating_div(zero);
+}
@@ -0,0 +1,7 @@
+use nutype::nutype;
+
+#[nutype(validate(multiple_of = 0.25), default = 0.3)]
+#[derive(Default)]
+pub struct Price(f64);
+
+fn main() {}
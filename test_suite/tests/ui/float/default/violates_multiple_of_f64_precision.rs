@@ -0,0 +1,8 @@
+use nutype::nutype;
+
+// Within the tolerance of `f32`, but not of `f64`
+#[nutype(validate(multiple_of = 0.1), default = 0.30000001)]
+#[derive(Default)]
+pub struct Price(f64);
+
+fn main() {}
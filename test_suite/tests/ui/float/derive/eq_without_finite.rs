@@ -0,0 +1,7 @@
+use nutype::nutype;
+
+#[nutype(validate(min = 0.0))]
+#[derive(PartialEq, Eq)]
+pub struct Price(f64);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use nutype::nutype;
+
+#[nutype]
+#[derive(PartialEq, Hash)]
+pub struct Weight(f32);
+
+fn main() {}
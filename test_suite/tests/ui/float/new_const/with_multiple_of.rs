@@ -0,0 +1,12 @@
+use nutype::nutype;
+
+// `multiple_of` for floats can't be checked in a const context,
+// so `new_const()` is not generated instead of skipping the check.
+#[nutype(validate(min = 0.0, multiple_of = 0.25))]
+pub struct Price(f64);
+
+const QUARTER: Price = Price::new_const(0.25);
+
+fn main() {
+    let _ = QUARTER;
+}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(clamp(0.0)))]
+pub struct Ratio(f64);
+
+fn main() {}
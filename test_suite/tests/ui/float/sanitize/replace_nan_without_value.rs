@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(replace_nan))]
+pub struct Balance(f64);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(round(4294967295)))]
+pub struct Price(f64);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(round(39)))]
+pub struct Ratio(f32);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(finite, not_nan))]
+pub struct Amount(f64);
+
+fn main() {}
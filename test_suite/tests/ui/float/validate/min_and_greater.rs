@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(min = 0.0, greater = 1.0))]
+pub struct Amount(f64);
+
+fn main() {}
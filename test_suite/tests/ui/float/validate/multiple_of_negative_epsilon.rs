@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(multiple_of(0.05, epsilon = -0.001)))]
+pub struct Price(f64);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use nutype::nutype;
+
+#[nutype(validate(greater = 0), default = 0)]
+#[derive(Default)]
+pub struct Amount(u32);
+
+fn main() {}
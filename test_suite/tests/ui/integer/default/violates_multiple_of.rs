@@ -0,0 +1,7 @@
+use nutype::nutype;
+
+#[nutype(validate(multiple_of = 5), default = 12)]
+#[derive(Default)]
+pub struct Minutes(u32);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(validate(max = 100))]
+#[derive(Deref)]
+pub struct Percentage(u32);
+
+fn main() {
+    let mut percentage = Percentage::new(100).unwrap();
+    *percentage += 1;
+}
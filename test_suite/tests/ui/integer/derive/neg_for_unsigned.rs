@@ -0,0 +1,7 @@
+use nutype::nutype;
+
+#[nutype(validate(max = 100))]
+#[derive(Neg)]
+pub struct Amount(u32);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(validate(min = 1024, max = 49151))]
+pub struct Port(u16);
+
+const HTTP: Port = Port::new_const(80);
+
+fn main() {
+    let _ = HTTP;
+}
@@ -0,0 +1,10 @@
+use nutype::nutype;
+
+#[nutype(sanitize(clamp(0, 100)))]
+pub struct Percentage(u8);
+
+const FULL: Percentage = Percentage::new_const(100);
+
+fn main() {
+    let _ = FULL;
+}
@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    sanitize(clamp(0, 100))
+    validate(min = 1, max = 100)
+)]
+pub struct PageSize(u32);
+
+fn main() {}
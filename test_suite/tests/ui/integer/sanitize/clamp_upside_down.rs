@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(clamp(100, 0)))]
+pub struct Percentage(u8);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater = 5, less = 5))]
+pub struct Amount(i32);
+
+fn main() {}
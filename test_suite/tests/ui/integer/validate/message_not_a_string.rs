@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(min(18, message = 42)))]
+pub struct Age(u8);
+
+fn main() {}
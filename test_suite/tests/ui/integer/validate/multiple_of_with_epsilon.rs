@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(multiple_of(6, epsilon = 1)))]
+pub struct Quantity(u32);
+
+fn main() {}
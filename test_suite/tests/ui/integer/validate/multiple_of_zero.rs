@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(multiple_of = 0))]
+pub struct Quantity(u32);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(validate(
+    greater = 0,
+    with(name = TooSmall, fn = |v: &i32| *v != 13),
+))]
+pub struct Amount(i32);
+
+fn main () {}
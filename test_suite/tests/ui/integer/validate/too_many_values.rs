@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(min(18, 21)))]
+pub struct Age(u8);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use nutype::nutype;
+
+#[nutype(default = "Anonymous", validate(max_len = 5))]
+#[derive(Default)]
+pub struct Name(String);
+
+fn main() {}
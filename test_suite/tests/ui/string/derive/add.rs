@@ -0,0 +1,7 @@
+use nutype::nutype;
+
+#[nutype]
+#[derive(Add)]
+pub struct Name(String);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[derive(Debug)]
+pub struct NameError;
+
+#[nutype(error = NameError, validate(not_empty, with = |_| Ok(())))]
+pub struct Name(String);
+
+fn main() {}
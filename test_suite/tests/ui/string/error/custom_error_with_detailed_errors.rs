@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[derive(Debug)]
+pub struct NameError;
+
+#[nutype(detailed_errors, error = NameError, validate(with = |_| Ok(())))]
+pub struct Name(String);
+
+fn main() {}
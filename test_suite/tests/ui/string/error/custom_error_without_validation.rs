@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[derive(Debug)]
+pub struct NameError;
+
+#[nutype(error = NameError, sanitize(trim))]
+pub struct Name(String);
+
+fn main() {}
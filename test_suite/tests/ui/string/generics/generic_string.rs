@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(max_len = 20))]
+pub struct Name<T>(String);
+
+fn main () {}
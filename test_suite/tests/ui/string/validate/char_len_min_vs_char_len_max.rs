@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(char_len_min = 10, char_len_max = 5))]
+pub struct Name(String);
+
+fn main () {}
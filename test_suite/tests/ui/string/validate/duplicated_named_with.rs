@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(validate(
+    with(name = NotReserved, fn = |s| s != "admin"),
+    with(name = NotReserved, fn = |s| s != "root"),
+))]
+pub struct Username(String);
+
+fn main () {}
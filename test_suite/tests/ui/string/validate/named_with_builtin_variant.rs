@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(validate(
+    not_empty,
+    with(name = Empty, fn = |s| s.trim() != ""),
+))]
+pub struct Username(String);
+
+fn main () {}
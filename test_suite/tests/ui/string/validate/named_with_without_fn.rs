@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(with(name = NotReserved)))]
+pub struct Username(String);
+
+fn main () {}